@@ -9,3 +9,35 @@ use uuid::Uuid;
 fn new_v4(b: &mut Bencher) {
     b.iter(|| Uuid::new_v4());
 }
+
+#[bench]
+fn new_v4_x1000(b: &mut Bencher) {
+    b.iter(|| {
+        let mut uuids = [Uuid::nil(); 1000];
+
+        for uuid in uuids.iter_mut() {
+            *uuid = Uuid::new_v4();
+        }
+
+        uuids
+    });
+}
+
+#[bench]
+fn fill_v4_x1000(b: &mut Bencher) {
+    b.iter(|| {
+        let mut uuids = [Uuid::nil(); 1000];
+        Uuid::fill_v4(&mut uuids);
+
+        uuids
+    });
+}
+
+#[bench]
+fn v4_iter_x1000(b: &mut Bencher) {
+    b.iter(|| {
+        Uuid::v4_iter()
+            .take(1000)
+            .fold(0u8, |acc, uuid| acc ^ uuid.as_bytes()[0])
+    });
+}
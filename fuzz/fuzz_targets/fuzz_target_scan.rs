@@ -0,0 +1,24 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use std::str;
+use uuid::{scan::Scanner, Uuid};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(haystack) = str::from_utf8(data) {
+        for scanner in &[Scanner::new(), Scanner::new().include_simple()] {
+            let mut last = 0;
+
+            // Ensure matches are in order, don't overlap, and parse to the
+            // UUID that was found
+            for (range, uuid) in scanner.find_iter(haystack) {
+                assert!(last <= range.start);
+                assert_eq!(Uuid::parse_lenient(&haystack[range.clone()]), Ok(uuid));
+
+                last = range.end;
+            }
+
+            let _ = scanner.replace_all(haystack, |_| "");
+        }
+    }
+});
@@ -22,6 +22,8 @@ extern crate std;
 #[macro_use]
 extern crate core as std;
 
+// Only the parser uses the error types here, so most kinds go unused
+#[allow(dead_code)]
 #[path = "../../shared/error.rs"]
 mod error;
 
@@ -4,33 +4,153 @@ use crate::std::fmt;
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Error(pub(crate) ErrorKind);
 
+/// The kind of an [`Error`], with details about what went wrong.
+///
+/// This can be used to map errors to machine-readable codes or to point at
+/// the part of the input that failed to parse. More kinds may be added in
+/// the future, so matches on this enum need a wildcard arm.
+///
+/// [`Error`]: struct.Error.html
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub(crate) enum ErrorKind {
+#[non_exhaustive]
+pub enum ErrorKind {
     /// Invalid character in the [`Uuid`] string.
     ///
     /// [`Uuid`]: ../struct.Uuid.html
-    Char { character: char, index: usize },
+    Char {
+        /// The invalid character.
+        character: char,
+        /// The 1-based position of the character in the input, counted in
+        /// bytes.
+        index: usize,
+    },
+    /// Invalid byte in the [`Uuid`] input that isn't part of a valid UTF-8
+    /// character, or that isn't ASCII in an encoded [`Uuid`] string, such as
+    /// Base32.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Byte {
+        /// The invalid byte.
+        byte: u8,
+        /// The 1-based position of the byte in the input.
+        index: usize,
+    },
     /// A simple [`Uuid`] didn't contain 32 characters.
     ///
     /// [`Uuid`]: ../struct.Uuid.html
-    SimpleLength { len: usize },
+    SimpleLength {
+        /// The number of characters found.
+        len: usize,
+    },
     /// A byte array didn't contain 16 bytes
-    #[allow(dead_code)]
-    ByteLength { len: usize },
+    ByteLength {
+        /// The number of bytes found.
+        len: usize,
+    },
     /// A hyphenated [`Uuid`] didn't contain 5 groups
     ///
     /// [`Uuid`]: ../struct.Uuid.html
-    GroupCount { count: usize },
+    GroupCount {
+        /// The number of groups found.
+        count: usize,
+    },
     /// A hyphenated [`Uuid`] had a group that wasn't the right length
     ///
     /// [`Uuid`]: ../struct.Uuid.html
     GroupLength {
+        /// The 0-based number of the group, from `0` to `4`.
         group: usize,
+        /// The number of characters found in the group.
         len: usize,
+        /// The 1-based position of the start of the group in the input,
+        /// counted in bytes.
+        index: usize,
+    },
+    /// A [`Uuid`] parsed from loosely formatted input didn't contain 32
+    /// hexadecimal digits
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    DigitCount {
+        /// The number of digits found.
+        count: usize,
+    },
+    /// A [`Uuid`] string wasn't in the canonical lowercase hyphenated format
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    NonCanonical {
+        /// The 1-based position of the first byte that differs from the
+        /// canonical format, counted in bytes. This is one past the end of
+        /// the input if it was too short.
         index: usize,
     },
+    /// A [`Uuid`] didn't use the variant defined by RFC 9562
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    UnknownVariant {
+        /// The three most significant bits of the byte holding the variant.
+        bits: u8,
+    },
+    /// A [`Uuid`] had a version that was rejected
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    RejectedVersion {
+        /// The version number stored in the UUID, from `0` to `15`.
+        version: u8,
+    },
+    /// An encoded [`Uuid`] string, such as Base32, didn't have the right
+    /// length
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    EncodedLength {
+        /// The expected length of the encoded string, in bytes.
+        expected: usize,
+        /// The number of bytes found.
+        len: usize,
+    },
+    /// Invalid character for the alphabet of an encoded [`Uuid`] string, such
+    /// as Base32
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    EncodedChar {
+        /// The invalid character.
+        character: char,
+        /// The 1-based position of the character in the input, counted in
+        /// bytes.
+        index: usize,
+    },
+    /// An encoded [`Uuid`] string, such as Base32, held a value that doesn't
+    /// fit in 128 bits
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    EncodedOverflow,
+    /// A custom field of a version 8 [`Uuid`] didn't fit in the bits
+    /// available to it
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    CustomFieldLength {
+        /// The name of the field: `custom_a`, `custom_b`, or `custom_c`.
+        field: &'static str,
+        /// The number of bits available to the field.
+        expected: u32,
+        /// The number of bits needed to store the value given for the field.
+        len: u32,
+    },
+    /// A time was outside of the range that can be stored in a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    TimestampRange,
+    /// The source of random numbers for a [`Uuid`] failed, with an error
+    /// code from `getrandom`
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Rng {
+        /// The error code reported by `getrandom`.
+        code: u32,
+    },
+    /// Every clock sequence for a single timestamp tick was used up by a
+    /// V1 context.
+    ClockSequenceExhausted,
     /// Some other error occurred.
-    #[allow(dead_code)]
     Other,
 }
 
@@ -42,12 +162,12 @@ pub(crate) enum ErrorKind {
 ///
 /// [`Uuid`]: ../struct.Uuid.html
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct InvalidUuid<'a>(pub(crate) &'a str);
+pub struct InvalidUuid<'a>(pub(crate) &'a [u8]);
 
 impl<'a> InvalidUuid<'a> {
     /// Converts the lightweight error type into detailed diagnostics.
     pub fn into_err(self) -> Error {
-        let (s, offset, simple) = match self.0.as_bytes() {
+        let (s, offset, simple) = match self.0 {
             [b'{', s @ .., b'}'] => (s, 1, false),
             [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..] => {
                 (s, "urn:uuid:".len(), false)
@@ -58,17 +178,24 @@ impl<'a> InvalidUuid<'a> {
         let mut hyphen_count = 0;
         let mut group_bounds = [0; 4];
 
-        // SAFETY: the byte array came from a valid utf8 string,
-        // and is aligned along char boundries.
-        let string = unsafe { std::str::from_utf8_unchecked(s) };
+        for (index, &byte) in s.iter().enumerate() {
+            if !byte.is_ascii() {
+                // Non-ASCII byte, which is reported as a char if it starts
+                // a valid UTF-8 sequence
+                let character = (1..=4)
+                    .filter_map(|len| s.get(index..index + len))
+                    .find_map(|bytes| std::str::from_utf8(bytes).ok())
+                    .and_then(|character| character.chars().next());
 
-        for (index, character) in string.char_indices() {
-            let byte = character as u8;
-            if character as u32 - byte as u32 > 0 {
-                // Multibyte char
-                return Error(ErrorKind::Char {
-                    character,
-                    index: index + offset + 1,
+                return Error(match character {
+                    Some(character) => ErrorKind::Char {
+                        character,
+                        index: index + offset + 1,
+                    },
+                    None => ErrorKind::Byte {
+                        byte,
+                        index: index + offset + 1,
+                    },
                 });
             } else if byte == b'-' {
                 // While we search, also count group breaks
@@ -127,6 +254,9 @@ impl fmt::Display for Error {
             } => {
                 write!(f, "invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-zA-Z], found `{}` at {}", character, index)
             }
+            ErrorKind::Byte { byte, index } => {
+                write!(f, "invalid byte: expected an optional prefix of `urn:uuid:` followed by [0-9a-zA-Z], found `{:#04x}` at {}", byte, index)
+            }
             ErrorKind::SimpleLength { len } => {
                 write!(
                     f,
@@ -148,6 +278,46 @@ impl fmt::Display for Error {
                     group, expected, len
                 )
             }
+            ErrorKind::DigitCount { count } => {
+                write!(f, "invalid digit count: expected 32, found {}", count)
+            }
+            ErrorKind::NonCanonical { index } => {
+                write!(f, "non-canonical UUID: expected lowercase hyphenated format, found a difference at {}", index)
+            }
+            ErrorKind::UnknownVariant { bits } => {
+                write!(f, "unknown variant: expected the RFC 9562 variant, found bits {:03b}", bits)
+            }
+            ErrorKind::RejectedVersion { version } => {
+                write!(f, "rejected version: found version {}", version)
+            }
+            ErrorKind::EncodedLength { expected, len } => {
+                write!(f, "invalid length: expected {} for the encoded UUID, found {}", expected, len)
+            }
+            ErrorKind::EncodedChar { character, index } => {
+                write!(f, "invalid character: expected a digit of the encoding's alphabet, found `{}` at {}", character, index)
+            }
+            ErrorKind::EncodedOverflow => {
+                write!(f, "invalid encoded UUID: the value doesn't fit in 128 bits")
+            }
+            ErrorKind::CustomFieldLength { field, expected, len } => {
+                write!(
+                    f,
+                    "invalid custom field length in {}: expected at most {} bits, found {}",
+                    field, expected, len
+                )
+            }
+            ErrorKind::TimestampRange => {
+                write!(f, "invalid timestamp: out of the range supported by UUIDs")
+            }
+            ErrorKind::Rng { code } => write!(
+                f,
+                "could not retrieve random bytes for uuid: error code {}",
+                code
+            ),
+            ErrorKind::ClockSequenceExhausted => write!(
+                f,
+                "clock sequence exhausted: too many UUIDs were created within a single 100 ns tick"
+            ),
             ErrorKind::Other => write!(f, "failed to parse a UUID"),
         }
     }
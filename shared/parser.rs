@@ -13,7 +13,12 @@ use crate::error::InvalidUuid;
 
 #[inline]
 pub const fn try_parse(input: &str) -> Result<[u8; 16], InvalidUuid> {
-    let result = match (input.len(), input.as_bytes()) {
+    try_parse_ascii(input.as_bytes())
+}
+
+#[inline]
+pub const fn try_parse_ascii(input: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
+    let result = match (input.len(), input) {
         // Inputs of 32 bytes must be a non-hyphenated UUID
         (32, s) => parse_simple(s),
         // Hyphenated UUIDs may be wrapped in various ways:
@@ -37,7 +42,7 @@ pub const fn try_parse(input: &str) -> Result<[u8; 16], InvalidUuid> {
 }
 
 #[inline]
-const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
+pub const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
     // This length check here removes all other bounds
     // checks in this function
     if s.len() != 32 {
@@ -69,7 +74,7 @@ const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
 }
 
 #[inline]
-const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
+pub const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
     // This length check here removes all other bounds
     // checks in this function
     if s.len() != 36 {
@@ -67,6 +67,31 @@ impl Uuid {
         Uuid::from_bytes([0; 16])
     }
 
+    /// The 'max UUID'.
+    ///
+    /// The max UUID is a special form of UUID that is specified to have all
+    /// 128 bits set to one, as defined in [IETF RFC 9562 Section 5.10][RFC].
+    /// It sorts after every other UUID, so it's useful as an upper bound.
+    ///
+    /// [RFC]: https://www.rfc-editor.org/rfc/rfc9562#section-5.10
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::max();
+    ///
+    /// assert_eq!(
+    ///     "ffffffff-ffff-ffff-ffff-ffffffffffff",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn max() -> Self {
+        Uuid::from_bytes([0xFF; 16])
+    }
+
     /// Creates a UUID from four field values.
     ///
     /// # Examples
@@ -540,6 +565,185 @@ impl Builder {
             .with_version(Version::Random)
     }
 
+    /// Creates a `Builder` for a version 7 UUID using the supplied Unix
+    /// timestamp in milliseconds and random bytes.
+    ///
+    /// This method can be useful in environments where the `v7` feature isn't
+    /// available. The lower 48 bits of `millis` are stored big-endian at the
+    /// start of the UUID so that UUIDs created later sort after ones created
+    /// earlier. This method will take care of setting the appropriate version
+    /// and variant fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// # let rng = || [
+    /// #     0x7c, 0xc3, 0x98, 0xc4, 0xdc, 0x0c, 0x0c, 0x07, 0x39, 0x8f,
+    /// # ];
+    /// let random_bytes = rng();
+    /// let uuid =
+    ///     Builder::from_unix_timestamp_millis(0x017f22e279b0, &random_bytes)
+    ///         .into_uuid();
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// assert_eq!(Variant::RFC4122, uuid.get_variant());
+    /// assert_eq!(
+    ///     "017f22e2-79b0-7cc3-98c4-dc0c0c07398f",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_millis(
+        millis: u64,
+        random_bytes: &[u8; 10],
+    ) -> Self {
+        Builder(Uuid::from_bytes([
+            (millis >> 40) as u8,
+            (millis >> 32) as u8,
+            (millis >> 24) as u8,
+            (millis >> 16) as u8,
+            (millis >> 8) as u8,
+            millis as u8,
+            random_bytes[0],
+            random_bytes[1],
+            random_bytes[2],
+            random_bytes[3],
+            random_bytes[4],
+            random_bytes[5],
+            random_bytes[6],
+            random_bytes[7],
+            random_bytes[8],
+            random_bytes[9],
+        ]))
+        .with_variant(Variant::RFC4122)
+        .with_version(Version::SortRand)
+    }
+
+    /// Creates a `Builder` for a version 8 UUID using the supplied
+    /// user-defined bytes.
+    ///
+    /// This method will take care of setting the appropriate version and
+    /// variant fields, overwriting the 6 bits of `b` that hold them. Use
+    /// [`Builder::from_custom_fields`] to avoid losing any custom data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// let uuid = Builder::from_custom_bytes([0xff; 16]).into_uuid();
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(Variant::RFC4122, uuid.get_variant());
+    /// assert_eq!(
+    ///     "ffffffff-ffff-8fff-bfff-ffffffffffff",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`Builder::from_custom_fields`]: #method.from_custom_fields
+    pub const fn from_custom_bytes(b: Bytes) -> Self {
+        Builder(Uuid::from_bytes(b))
+            .with_variant(Variant::RFC4122)
+            .with_version(Version::Custom)
+    }
+
+    /// Creates a `Builder` for a version 8 UUID using the supplied custom
+    /// fields.
+    ///
+    /// A version 8 UUID has 122 bits available for custom data, split into
+    /// three fields around the version and variant:
+    ///
+    /// * `custom_a`: the first 48 bits of the UUID.
+    /// * `custom_b`: the 12 bits following the version.
+    /// * `custom_c`: the 62 bits following the variant.
+    ///
+    /// Each field is stored big-endian, so UUIDs sort by `custom_a`, then
+    /// `custom_b`, then `custom_c`. The fields can be read back with
+    /// [`Uuid::get_custom_fields`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any field has bits set beyond
+    /// the width available to it, rather than silently discarding them or
+    /// letting them overwrite the version and variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Builder::from_custom_fields(
+    ///     0x2489_e9ad_2ee2,
+    ///     0xe00,
+    ///     0x0ec9_32d5_f691_81c0,
+    /// )?
+    /// .into_uuid();
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(Variant::RFC4122, uuid.get_variant());
+    /// assert_eq!(
+    ///     "2489e9ad-2ee2-8e00-8ec9-32d5f69181c0",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// A field that's too wide is an error:
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// assert!(Builder::from_custom_fields(0, 0x1000, 0).is_err());
+    /// ```
+    ///
+    /// [`Uuid::get_custom_fields`]: struct.Uuid.html#method.get_custom_fields
+    pub const fn from_custom_fields(
+        custom_a: u64,
+        custom_b: u16,
+        custom_c: u64,
+    ) -> Result<Self, Error> {
+        if custom_a >> 48 != 0 {
+            return Err(Error(ErrorKind::CustomFieldLength {
+                field: "custom_a",
+                expected: 48,
+                len: 64 - custom_a.leading_zeros(),
+            }));
+        }
+        if custom_b >> 12 != 0 {
+            return Err(Error(ErrorKind::CustomFieldLength {
+                field: "custom_b",
+                expected: 12,
+                len: 16 - custom_b.leading_zeros(),
+            }));
+        }
+        if custom_c >> 62 != 0 {
+            return Err(Error(ErrorKind::CustomFieldLength {
+                field: "custom_c",
+                expected: 62,
+                len: 64 - custom_c.leading_zeros(),
+            }));
+        }
+
+        Ok(Builder::from_custom_bytes([
+            (custom_a >> 40) as u8,
+            (custom_a >> 32) as u8,
+            (custom_a >> 24) as u8,
+            (custom_a >> 16) as u8,
+            (custom_a >> 8) as u8,
+            custom_a as u8,
+            (custom_b >> 8) as u8,
+            custom_b as u8,
+            (custom_c >> 56) as u8,
+            (custom_c >> 48) as u8,
+            (custom_c >> 40) as u8,
+            (custom_c >> 32) as u8,
+            (custom_c >> 24) as u8,
+            (custom_c >> 16) as u8,
+            (custom_c >> 8) as u8,
+            custom_c as u8,
+        ]))
+    }
+
     /// Creates a `Builder` using the supplied bytes.
     ///
     /// # Errors
@@ -722,6 +926,27 @@ impl Builder {
         Builder(Uuid::nil())
     }
 
+    /// Creates a `Builder` with an initial [`Uuid::max`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Builder;
+    /// let uuid = Builder::max().into_uuid();
+    ///
+    /// assert_eq!(
+    ///     "ffffffff-ffff-ffff-ffff-ffffffffffff",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`Uuid::max`]: struct.Uuid.html#method.max
+    pub const fn max() -> Self {
+        Builder(Uuid::max())
+    }
+
     /// Specifies the variant of the UUID.
     pub fn set_variant(&mut self, v: Variant) -> &mut Self {
         *self = Builder(self.0).with_variant(v);
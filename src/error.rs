@@ -3,3 +3,145 @@
 mod imp;
 
 pub use self::imp::*;
+
+impl Error {
+    /// Returns the kind of error that occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ErrorKind, Uuid};
+    /// let err = Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c").unwrap_err();
+    ///
+    /// match err.kind() {
+    ///     ErrorKind::SimpleLength { len } => assert_eq!(31, *len),
+    ///     _ => panic!("unexpected error: {}", err),
+    /// }
+    /// ```
+    pub const fn kind(&self) -> &ErrorKind {
+        &self.0
+    }
+
+    /// Returns the invalid character, if the error was caused by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let err = Uuid::parse_str("67e55044-z0b1-426f-9247-bb680e5fe0c8").unwrap_err();
+    ///
+    /// assert_eq!(Some('z'), err.invalid_char());
+    /// assert_eq!(Some(10), err.index());
+    /// ```
+    pub const fn invalid_char(&self) -> Option<char> {
+        match self.0 {
+            ErrorKind::Char { character, .. }
+            | ErrorKind::EncodedChar { character, .. } => Some(character),
+            _ => None,
+        }
+    }
+
+    /// Returns the invalid byte, if the error was caused by input that
+    /// wasn't valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let err = Uuid::parse_ascii(b"67e55044-\x80\x80b1-426f-9247-bb680e5fe0c8").unwrap_err();
+    ///
+    /// assert_eq!(Some(0x80), err.invalid_byte());
+    /// assert_eq!(Some(10), err.index());
+    /// ```
+    pub const fn invalid_byte(&self) -> Option<u8> {
+        match self.0 {
+            ErrorKind::Byte { byte, .. } => Some(byte),
+            _ => None,
+        }
+    }
+
+    /// Returns the 1-based position in the input, counted in bytes, of the
+    /// invalid character or byte, of the start of the group with the wrong
+    /// length, or of the first byte that isn't in canonical format, if the
+    /// error was caused by one.
+    pub const fn index(&self) -> Option<usize> {
+        match self.0 {
+            ErrorKind::Char { index, .. }
+            | ErrorKind::Byte { index, .. }
+            | ErrorKind::EncodedChar { index, .. }
+            | ErrorKind::GroupLength { index, .. }
+            | ErrorKind::NonCanonical { index } => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Returns the 0-based number of the group with the wrong length, if the
+    /// error was caused by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let err = Uuid::parse_str("67e55044-10b1-426-9247-bb680e5fe0c8").unwrap_err();
+    ///
+    /// assert_eq!(Some(2), err.group());
+    /// assert_eq!(Some(15), err.index());
+    /// ```
+    pub const fn group(&self) -> Option<usize> {
+        match self.0 {
+            ErrorKind::GroupLength { group, .. } => Some(group),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use crate::Uuid;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_accessors() {
+        let err = Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0cz}")
+            .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::Char {
+                character: 'z',
+                index: 37,
+            }
+        );
+        assert_eq!(err.invalid_char(), Some('z'));
+        assert_eq!(err.index(), Some(37));
+        assert_eq!(err.group(), None);
+
+        let err =
+            Uuid::parse_str("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c")
+                .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::GroupLength {
+                group: 4,
+                len: 11,
+                index: 34,
+            }
+        );
+        assert_eq!(err.invalid_char(), None);
+        assert_eq!(err.index(), Some(34));
+        assert_eq!(err.group(), Some(4));
+
+        let err = Uuid::parse_str("67e55044-10b1-426f-9247").unwrap_err();
+
+        assert_eq!(err.kind(), &ErrorKind::GroupCount { count: 4 });
+        assert_eq!(err.invalid_char(), None);
+        assert_eq!(err.index(), None);
+        assert_eq!(err.group(), None);
+    }
+}
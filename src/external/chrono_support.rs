@@ -0,0 +1,138 @@
+use crate::{error::*, std::convert::TryFrom, timestamp::Timestamp};
+
+use chrono::{DateTime, TimeZone, Utc};
+
+impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for Timestamp {
+    type Error = Error;
+
+    /// Converts a `DateTime` into a `Timestamp` with a counter of zero.
+    ///
+    /// The time is truncated to the 100-nanosecond precision of a
+    /// `Timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the time is before 15 Oct 1582, or too far
+    /// in the future to fit in the 60 bits available to a UUID timestamp.
+    fn try_from(datetime: DateTime<Tz>) -> Result<Self, Self::Error> {
+        Timestamp::try_from_unix_signed(
+            datetime.timestamp(),
+            datetime.timestamp_subsec_nanos(),
+        )
+    }
+}
+
+impl From<Timestamp> for DateTime<Utc> {
+    /// Converts a `Timestamp` into the `DateTime` it represents.
+    ///
+    /// Unlike [`Timestamp::to_unix`], this also supports times before the
+    /// Unix epoch.
+    ///
+    /// [`Timestamp::to_unix`]: #method.to_unix
+    fn from(ts: Timestamp) -> Self {
+        let (seconds, subsec_nanos) = ts.to_unix_signed();
+
+        // Any `u64` number of ticks is before the year 60039, well within the
+        // range `chrono` supports
+        Utc.timestamp_opt(seconds, subsec_nanos).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use chrono::{FixedOffset, NaiveDate};
+
+    fn utc(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        nanos: u32,
+    ) -> DateTime<Utc> {
+        let naive = NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_nano_opt(hour, min, sec, nanos)
+            .unwrap();
+
+        Utc.from_utc_datetime(&naive)
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_roundtrip() {
+        for &datetime in &[
+            utc(1582, 10, 15, 0, 0, 0, 0),
+            utc(1969, 12, 31, 23, 59, 59, 999_999_900),
+            utc(1970, 1, 1, 0, 0, 0, 0),
+            utc(2022, 2, 22, 19, 22, 22, 0),
+            utc(5236, 3, 31, 0, 0, 0, 0),
+        ] {
+            let ts = Timestamp::try_from(datetime).unwrap();
+
+            assert_eq!(DateTime::<Utc>::from(ts), datetime);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_gregorian_epoch() {
+        let ts = Timestamp::try_from(utc(1582, 10, 15, 0, 0, 0, 0)).unwrap();
+
+        assert_eq!(ts.to_rfc4122(), (0, 0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_rfc_vector() {
+        // From RFC 9562, Appendix A.1
+        let ts = Timestamp::from_rfc4122(0x1EC_9414_C232_AB00, 0);
+
+        assert_eq!(DateTime::<Utc>::from(ts), utc(2022, 2, 22, 19, 22, 22, 0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_max_ticks() {
+        let ts = Timestamp::from_rfc4122(u64::MAX, 0);
+
+        assert_eq!(
+            DateTime::<Utc>::from(ts),
+            utc(60038, 3, 11, 5, 36, 10, 955_161_500)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_offset() {
+        let offset = FixedOffset::east_opt(5 * 60 * 60).unwrap();
+        let datetime = utc(2022, 2, 22, 19, 22, 22, 0);
+
+        assert_eq!(
+            Timestamp::try_from(datetime.with_timezone(&offset)).unwrap(),
+            Timestamp::try_from(datetime).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_out_of_range() {
+        assert!(Timestamp::try_from(utc(
+            1582,
+            10,
+            14,
+            23,
+            59,
+            59,
+            999_999_999
+        ))
+        .is_err());
+        assert!(Timestamp::try_from(utc(5237, 1, 1, 0, 0, 0, 0)).is_err());
+    }
+}
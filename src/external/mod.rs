@@ -1,6 +1,10 @@
 #[cfg(feature = "arbitrary")]
 pub(crate) mod arbitrary_support;
+#[cfg(feature = "chrono")]
+pub(crate) mod chrono_support;
 #[cfg(feature = "serde")]
 pub(crate) mod serde_support;
 #[cfg(feature = "slog")]
 pub(crate) mod slog_support;
+#[cfg(feature = "time")]
+pub(crate) mod time_support;
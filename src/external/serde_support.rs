@@ -11,7 +11,7 @@
 
 use crate::{
     error::*,
-    fmt::{Braced, Hyphenated, Simple, Urn},
+    fmt::{Base32, Base64Url, Braced, Hyphenated, Simple, Urn},
     std::fmt,
     Uuid,
 };
@@ -71,6 +71,24 @@ impl Serialize for Braced {
     }
 }
 
+impl Serialize for Base32 {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.encode_upper(&mut Uuid::encode_buffer()))
+    }
+}
+
+impl Serialize for Base64Url {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.encode(&mut Uuid::encode_buffer()))
+    }
+}
+
 impl<'de> Deserialize<'de> for Uuid {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
@@ -244,6 +262,108 @@ pub mod compact {
     }
 }
 
+pub mod base64url {
+    //! Serialize a [`Uuid`] as an unpadded, URL-safe Base64 string.
+    //!
+    //! This is a 22 character string, like the one written by
+    //! [`Uuid::base64url`].
+    //!
+    //! [`Uuid`]: ../../struct.Uuid.html
+    //! [`Uuid::base64url`]: ../../struct.Uuid.html#method.base64url
+
+    use crate::{std::fmt, Uuid};
+    use serde::de;
+
+    /// Serialize from a [`Uuid`] as an unpadded, URL-safe Base64 string
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(u.as_base64url(), serializer)
+    }
+
+    /// Deserialize an unpadded, URL-safe Base64 string as a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Base64UrlVisitor;
+
+        impl<'vi> de::Visitor<'vi> for Base64UrlVisitor {
+            type Value = Uuid;
+
+            fn expecting(
+                &self,
+                formatter: &mut fmt::Formatter<'_>,
+            ) -> fmt::Result {
+                write!(formatter, "a base64url UUID string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
+                Uuid::parse_base64url(value).map_err(|e| {
+                    E::custom(format_args!("UUID parsing failed: {}", e))
+                })
+            }
+        }
+
+        deserializer.deserialize_str(Base64UrlVisitor)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_derive::*;
+        use serde_test::{self, Configure, Token};
+
+        #[derive(Serialize, Debug, Deserialize, PartialEq, Clone, Copy)]
+        struct UuidContainer {
+            #[serde(with = "crate::serde::base64url")]
+            u: crate::Uuid,
+        }
+
+        #[test]
+        fn test_serialize_base64url() {
+            let container = UuidContainer {
+                u: crate::Uuid::parse_str(
+                    "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+                )
+                .unwrap(),
+            };
+
+            let tokens = [
+                Token::Struct {
+                    name: "UuidContainer",
+                    len: 1,
+                },
+                Token::Str("u"),
+                Token::Str("-RaMXs6yT6q2vzKb85-h5A"),
+                Token::StructEnd,
+            ];
+
+            serde_test::assert_tokens(&container.readable(), &tokens);
+            serde_test::assert_tokens(&container.compact(), &tokens);
+        }
+
+        #[test]
+        fn test_de_failure() {
+            serde_test::assert_de_tokens_error::<UuidContainer>(
+                &[
+                    Token::Struct {
+                        name: "UuidContainer",
+                        len: 1,
+                    },
+                    Token::Str("u"),
+                    Token::Str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"),
+                ],
+                "UUID parsing failed: invalid length: expected 22 for the encoded UUID, found 36",
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod serde_tests {
     use super::*;
@@ -326,6 +446,38 @@ mod serde_tests {
         serde_test::assert_ser_tokens(&u.braced(), &[Token::Str(uuid_str)]);
     }
 
+    #[test]
+    fn test_serialize_base32() {
+        let u =
+            Uuid::parse_str("01563e3a-b5d3-d676-4c61-efb99302bd5b").unwrap();
+        serde_test::assert_ser_tokens(
+            &u.base32(),
+            &[Token::Str("01ARZ3NDEKTSV4RRFFQ69G5FAV")],
+        );
+    }
+
+    #[test]
+    fn test_serialize_base32_bounds() {
+        serde_test::assert_ser_tokens(
+            &Uuid::nil().base32(),
+            &[Token::Str("00000000000000000000000000")],
+        );
+        serde_test::assert_ser_tokens(
+            &Uuid::max().base32(),
+            &[Token::Str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ")],
+        );
+    }
+
+    #[test]
+    fn test_serialize_base64url() {
+        let u =
+            Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+        serde_test::assert_ser_tokens(
+            &u.base64url(),
+            &[Token::Str("-RaMXs6yT6q2vzKb85-h5A")],
+        );
+    }
+
     #[test]
     fn test_serialize_non_human_readable() {
         let uuid_bytes = b"F9168C5E-CEB2-4F";
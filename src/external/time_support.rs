@@ -0,0 +1,146 @@
+use crate::{error::*, std::convert::TryFrom, timestamp::Timestamp};
+
+use time::OffsetDateTime;
+
+impl TryFrom<OffsetDateTime> for Timestamp {
+    type Error = Error;
+
+    /// Converts an `OffsetDateTime` into a `Timestamp` with a counter of
+    /// zero.
+    ///
+    /// The time is truncated to the 100-nanosecond precision of a
+    /// `Timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the time is before 15 Oct 1582, or too far
+    /// in the future to fit in the 60 bits available to a UUID timestamp.
+    fn try_from(datetime: OffsetDateTime) -> Result<Self, Self::Error> {
+        Timestamp::try_from_unix_signed(
+            datetime.unix_timestamp(),
+            datetime.nanosecond(),
+        )
+    }
+}
+
+impl TryFrom<Timestamp> for OffsetDateTime {
+    type Error = Error;
+
+    /// Converts a `Timestamp` into the `OffsetDateTime` it represents, in
+    /// UTC.
+    ///
+    /// Unlike [`Timestamp::to_unix`], this also supports times before the
+    /// Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the timestamp is after the year 9999, which
+    /// is only possible for timestamps that don't fit in the 60 bits
+    /// available to a UUID timestamp.
+    ///
+    /// [`Timestamp::to_unix`]: #method.to_unix
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        let (seconds, subsec_nanos) = ts.to_unix_signed();
+
+        OffsetDateTime::from_unix_timestamp_nanos(
+            seconds as i128 * 1_000_000_000 + subsec_nanos as i128,
+        )
+        .map_err(|_| Error(ErrorKind::TimestampRange))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    fn utc(
+        year: i32,
+        month: Month,
+        day: u8,
+        hour: u8,
+        min: u8,
+        sec: u8,
+        nanos: u32,
+    ) -> OffsetDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, month, day).unwrap(),
+            Time::from_hms_nano(hour, min, sec, nanos).unwrap(),
+        )
+        .assume_utc()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_roundtrip() {
+        for &datetime in &[
+            utc(1582, Month::October, 15, 0, 0, 0, 0),
+            utc(1969, Month::December, 31, 23, 59, 59, 999_999_900),
+            utc(1970, Month::January, 1, 0, 0, 0, 0),
+            utc(2022, Month::February, 22, 19, 22, 22, 0),
+            utc(5236, Month::March, 31, 0, 0, 0, 0),
+        ] {
+            let ts = Timestamp::try_from(datetime).unwrap();
+
+            assert_eq!(OffsetDateTime::try_from(ts).unwrap(), datetime);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_rfc_vector() {
+        // From RFC 9562, Appendix A.1
+        let ts = Timestamp::from_rfc4122(0x1EC_9414_C232_AB00, 0);
+
+        assert_eq!(
+            OffsetDateTime::try_from(ts).unwrap(),
+            utc(2022, Month::February, 22, 19, 22, 22, 0)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_offset() {
+        let offset = UtcOffset::from_hms(-7, 0, 0).unwrap();
+        let datetime = utc(2022, Month::February, 22, 19, 22, 22, 0);
+
+        assert_eq!(
+            Timestamp::try_from(datetime.to_offset(offset)).unwrap(),
+            Timestamp::try_from(datetime).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_out_of_range() {
+        assert!(Timestamp::try_from(utc(
+            1582,
+            Month::October,
+            14,
+            23,
+            59,
+            59,
+            999_999_999
+        ))
+        .is_err());
+        assert!(
+            Timestamp::try_from(utc(5237, Month::January, 1, 0, 0, 0, 0))
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_offset_date_time_out_of_range() {
+        let ts = Timestamp::from_rfc4122(u64::MAX, 0);
+
+        assert_eq!(
+            OffsetDateTime::try_from(ts),
+            Err(Error(ErrorKind::TimestampRange))
+        );
+    }
+}
@@ -97,6 +97,60 @@ pub struct Urn(Uuid);
 #[repr(transparent)]
 pub struct Braced(Uuid);
 
+/// An adapter for formatting an [`Uuid`] as a Crockford Base32 string.
+///
+/// The string is 26 characters long, and is the same as the text form of a
+/// [ULID] with the same 128 bits. It's formatted in upper-case by `Display`.
+///
+/// Takes an owned instance of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+/// [ULID]: https://github.com/ulid/spec
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base32(Uuid);
+
+/// An adapter for formatting an [`Uuid`] as an unpadded, URL-safe Base64
+/// string.
+///
+/// The string is 22 characters long, using the `base64url` alphabet from
+/// [RFC 4648] without `=` padding.
+///
+/// Takes an owned instance of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+/// [RFC 4648]: https://www.rfc-editor.org/rfc/rfc4648#section-5
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base64Url(Uuid);
+
+/// An adapter for formatting an [`Uuid`] as a Base58 string, using the
+/// Bitcoin alphabet.
+///
+/// The alphabet leaves out the visually ambiguous characters `0`, `O`, `I`,
+/// and `l`. The string is always 22 characters long, padded at the start
+/// with `1`, the Base58 zero digit, so strings sort in the same order as
+/// their UUIDs.
+///
+/// Takes an owned instance of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base58(Uuid);
+
+/// An adapter for formatting an [`Uuid`] as a Base36 string.
+///
+/// The string is always 25 characters long, padded at the start with `0`, so
+/// strings of the same case sort in the same order as their UUIDs.
+///
+/// Takes an owned instance of the [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base36(Uuid);
+
 impl Uuid {
     /// Get a [`Hyphenated`] formatter.
     ///
@@ -169,6 +223,74 @@ impl Uuid {
         // SAFETY: `Uuid` and `Braced` have the same ABI
         unsafe { &*(self as *const Uuid as *const Braced) }
     }
+
+    /// Get a [`Base32`] formatter.
+    ///
+    /// [`Base32`]: adapter/struct.Base32.html
+    #[inline]
+    pub const fn base32(self) -> Base32 {
+        Base32(self)
+    }
+
+    /// Get a borrowed [`Base32`] formatter.
+    ///
+    /// [`Base32`]: adapter/struct.Base32.html
+    #[inline]
+    pub fn as_base32(&self) -> &Base32 {
+        // SAFETY: `Uuid` and `Base32` have the same ABI
+        unsafe { &*(self as *const Uuid as *const Base32) }
+    }
+
+    /// Get a [`Base64Url`] formatter.
+    ///
+    /// [`Base64Url`]: adapter/struct.Base64Url.html
+    #[inline]
+    pub const fn base64url(self) -> Base64Url {
+        Base64Url(self)
+    }
+
+    /// Get a borrowed [`Base64Url`] formatter.
+    ///
+    /// [`Base64Url`]: adapter/struct.Base64Url.html
+    #[inline]
+    pub fn as_base64url(&self) -> &Base64Url {
+        // SAFETY: `Uuid` and `Base64Url` have the same ABI
+        unsafe { &*(self as *const Uuid as *const Base64Url) }
+    }
+
+    /// Get a [`Base58`] formatter.
+    ///
+    /// [`Base58`]: adapter/struct.Base58.html
+    #[inline]
+    pub const fn base58(self) -> Base58 {
+        Base58(self)
+    }
+
+    /// Get a borrowed [`Base58`] formatter.
+    ///
+    /// [`Base58`]: adapter/struct.Base58.html
+    #[inline]
+    pub fn as_base58(&self) -> &Base58 {
+        // SAFETY: `Uuid` and `Base58` have the same ABI
+        unsafe { &*(self as *const Uuid as *const Base58) }
+    }
+
+    /// Get a [`Base36`] formatter.
+    ///
+    /// [`Base36`]: adapter/struct.Base36.html
+    #[inline]
+    pub const fn base36(self) -> Base36 {
+        Base36(self)
+    }
+
+    /// Get a borrowed [`Base36`] formatter.
+    ///
+    /// [`Base36`]: adapter/struct.Base36.html
+    #[inline]
+    pub fn as_base36(&self) -> &Base36 {
+        // SAFETY: `Uuid` and `Base36` have the same ABI
+        unsafe { &*(self as *const Uuid as *const Base36) }
+    }
 }
 
 const UPPER: [u8; 16] = [
@@ -221,6 +343,66 @@ const fn format_hyphenated(src: &[u8; 16], upper: bool) -> [u8; 36] {
     dst
 }
 
+const BASE32_UPPER: [u8; 32] = *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const BASE32_LOWER: [u8; 32] = *b"0123456789abcdefghjkmnpqrstvwxyz";
+
+#[inline]
+const fn format_base32(src: &[u8; 16], upper: bool) -> [u8; 26] {
+    let lut = if upper { &BASE32_UPPER } else { &BASE32_LOWER };
+    let value = u128::from_be_bytes(*src);
+    let mut dst = [0; 26];
+    let mut i = 0;
+    while i < 26 {
+        // The first digit only holds the top 3 bits, so the string is
+        // padded at the start like a ULID
+        let shift = 125 - 5 * i as u32;
+        dst[i] = lut[((value >> shift) & 0x1f) as usize];
+        i += 1;
+    }
+    dst
+}
+
+const BASE64URL: [u8; 64] =
+    *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+#[inline]
+const fn format_base64url(src: &[u8; 16]) -> [u8; 22] {
+    let value = u128::from_be_bytes(*src);
+    let mut dst = [0; 22];
+    let mut i = 0;
+    while i < 21 {
+        let shift = 122 - 6 * i as u32;
+        dst[i] = BASE64URL[((value >> shift) & 0x3f) as usize];
+        i += 1;
+    }
+    // The last digit holds the lowest 2 bits, padded with zeros
+    dst[21] = BASE64URL[((value & 0x03) << 4) as usize];
+    dst
+}
+
+const BASE58: [u8; 58] =
+    *b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const BASE36_UPPER: [u8; 36] = *b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const BASE36_LOWER: [u8; 36] = *b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Writes the digits of `src` in the base of `lut`, padded with its zero
+/// digit to fill the first `len` characters. `len` can be at most 25, which
+/// is enough for any base from 36 up.
+#[inline]
+const fn format_base(src: &[u8; 16], lut: &[u8], len: usize) -> [u8; 25] {
+    let base = lut.len() as u128;
+    let mut value = u128::from_be_bytes(*src);
+    let mut dst = [lut[0]; 25];
+    let mut i = len;
+    while value != 0 {
+        i -= 1;
+        dst[i] = lut[(value % base) as usize];
+        value /= base;
+    }
+    dst
+}
+
 #[inline]
 fn encode_simple<'b>(
     src: &[u8; 16],
@@ -294,6 +476,63 @@ fn encode_urn<'b>(
     }
 }
 
+#[inline]
+fn encode_base32<'b>(
+    src: &[u8; 16],
+    buffer: &'b mut [u8],
+    upper: bool,
+) -> &'b mut str {
+    let buf = &mut buffer[..Base32::LENGTH];
+    let dst = buf.as_mut_ptr();
+
+    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe {
+        ptr::write(dst.cast(), format_base32(src, upper));
+        str::from_utf8_unchecked_mut(buf)
+    }
+}
+
+#[inline]
+fn encode_base64url<'b>(src: &[u8; 16], buffer: &'b mut [u8]) -> &'b mut str {
+    let buf = &mut buffer[..Base64Url::LENGTH];
+    let dst = buf.as_mut_ptr();
+
+    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe {
+        ptr::write(dst.cast(), format_base64url(src));
+        str::from_utf8_unchecked_mut(buf)
+    }
+}
+
+#[inline]
+fn encode_base58<'b>(src: &[u8; 16], buffer: &'b mut [u8]) -> &'b mut str {
+    let buf = &mut buffer[..Base58::LENGTH];
+    buf.copy_from_slice(
+        &format_base(src, &BASE58, Base58::LENGTH)[..Base58::LENGTH],
+    );
+
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe { str::from_utf8_unchecked_mut(buf) }
+}
+
+#[inline]
+fn encode_base36<'b>(
+    src: &[u8; 16],
+    buffer: &'b mut [u8],
+    upper: bool,
+) -> &'b mut str {
+    let lut = if upper { &BASE36_UPPER } else { &BASE36_LOWER };
+    let buf = &mut buffer[..Base36::LENGTH];
+    buf.copy_from_slice(
+        &format_base(src, lut, Base36::LENGTH)[..Base36::LENGTH],
+    );
+
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe { str::from_utf8_unchecked_mut(buf) }
+}
+
 impl Hyphenated {
     /// The length of a hyphenated [`Uuid`] string.
     ///
@@ -877,48 +1116,477 @@ impl Urn {
     }
 }
 
-macro_rules! impl_fmt_traits {
-    ($($T:ident<$($a:lifetime),*>),+) => {$(
-        impl<$($a),*> fmt::Display for $T<$($a),*> {
-            #[inline]
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt::LowerHex::fmt(self, f)
-            }
-        }
+impl Base32 {
+    /// The length of a Crockford Base32 [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 26;
 
-        impl<$($a),*> fmt::LowerHex for $T<$($a),*> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
-            }
-        }
+    /// Creates a [`Base32`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Base32`]: struct.Base32.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base32(uuid)
+    }
 
-        impl<$($a),*> fmt::UpperHex for $T<$($a),*> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
-            }
-        }
+    /// Writes the [`Uuid`] as a lower-case Crockford Base32 string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("01563e3a-b5d3-d676-4c61-efb99302bd5b")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.base32().encode_lower(&mut Uuid::encode_buffer()),
+    ///         "01arz3ndektsv4rrffq69g5fav"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(self.0.as_bytes(), buffer, false)
+    }
 
-        impl_fmt_from!($T<$($a),*>);
-    )+}
+    /// Writes the [`Uuid`] as an upper-case Crockford Base32 string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// This is the same as the text form of a ULID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("01563e3a-b5d3-d676-4c61-efb99302bd5b")?;
+    ///
+    ///     // the buffer is mutated directly, and trailing contents remains
+    ///     let mut buf = [b'!'; 30];
+    ///     assert_eq!(
+    ///         uuid.base32().encode_upper(&mut buf),
+    ///         "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+    ///     );
+    ///     assert_eq!(&buf as &[_], b"01ARZ3NDEKTSV4RRFFQ69G5FAV!!!!" as &[_]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(self.0.as_bytes(), buffer, true)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base32 = Uuid::nil().base32();
+    /// assert_eq!(*base32.as_uuid(), Uuid::nil());
+    /// ```
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base32`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base32 = Uuid::nil().base32();
+    /// assert_eq!(base32.into_uuid(), Uuid::nil());
+    /// ```
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
 }
 
-macro_rules! impl_fmt_from {
-    ($T:ident<>) => {
-        impl From<Uuid> for $T {
-            #[inline]
-            fn from(f: Uuid) -> Self {
-                $T(f)
-            }
-        }
+impl fmt::Display for Base32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
+    }
+}
 
-        impl From<$T> for Uuid {
-            #[inline]
-            fn from(f: $T) -> Self {
-                f.into_uuid()
-            }
-        }
+impl Base64Url {
+    /// The length of an unpadded Base64 [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 22;
 
-        impl AsRef<Uuid> for $T {
+    /// Creates a [`Base64Url`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Base64Url`]: struct.Base64Url.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base64Url(uuid)
+    }
+
+    /// Writes the [`Uuid`] as an unpadded, URL-safe Base64 string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded UUID.
+    ///
+    /// Base64 is case-sensitive, so unlike the hexadecimal formats there is
+    /// only one way to encode a UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?;
+    ///
+    ///     // the encoded portion is returned
+    ///     assert_eq!(
+    ///         uuid.base64url().encode(&mut Uuid::encode_buffer()),
+    ///         "-RaMXs6yT6q2vzKb85-h5A"
+    ///     );
+    ///
+    ///     // the buffer is mutated directly, and trailing contents remains
+    ///     let mut buf = [b'!'; 26];
+    ///     uuid.base64url().encode(&mut buf);
+    ///     assert_eq!(&buf as &[_], b"-RaMXs6yT6q2vzKb85-h5A!!!!" as &[_]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base64url(self.0.as_bytes(), buffer)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base64url = Uuid::nil().base64url();
+    /// assert_eq!(*base64url.as_uuid(), Uuid::nil());
+    /// ```
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base64Url`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base64url = Uuid::nil().base64url();
+    /// assert_eq!(base64url.into_uuid(), Uuid::nil());
+    /// ```
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for Base64Url {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl Base58 {
+    /// The length of a Base58 [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 22;
+
+    /// Creates a [`Base58`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Base58`]: struct.Base58.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base58(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a Base58 string to `buffer`, and returns the
+    /// subslice of the buffer that contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.base58().encode(&mut Uuid::encode_buffer()),
+    ///         "XkzTFVH2jeQ8TxAEFtaPkw"
+    ///     );
+    ///
+    ///     // short values are padded with the zero digit
+    ///     assert_eq!(
+    ///         Uuid::from_u128(1).base58().encode(&mut Uuid::encode_buffer()),
+    ///         "1111111111111111111112"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base58(self.0.as_bytes(), buffer)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base58 = Uuid::nil().base58();
+    /// assert_eq!(*base58.as_uuid(), Uuid::nil());
+    /// ```
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base58`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base58 = Uuid::nil().base58();
+    /// assert_eq!(base58.into_uuid(), Uuid::nil());
+    /// ```
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for Base58 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::LENGTH]))
+    }
+}
+
+impl Base36 {
+    /// The length of a Base36 [`Uuid`] string.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub const LENGTH: usize = 25;
+
+    /// Creates a [`Base36`] from a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`Base36`]: struct.Base36.html
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Base36(uuid)
+    }
+
+    /// Writes the [`Uuid`] as a lower-case Base36 string to `buffer`, and
+    /// returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.base36().encode_lower(&mut Uuid::encode_buffer()),
+    ///         "eqvm63wmwpmkfd33ih5dihg4k"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base36(self.0.as_bytes(), buffer, false)
+    }
+
+    /// Writes the [`Uuid`] as an upper-case Base36 string to `buffer`, and
+    /// returns the subslice of the buffer that contains the encoded UUID.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`LENGTH`]. [`Uuid::encode_buffer`] can be used to get a
+    /// sufficiently-large temporary buffer.
+    ///
+    /// [`LENGTH`]: #associatedconstant.LENGTH
+    /// [`Uuid::encode_buffer`]: ../struct.Uuid.html#method.encode_buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.base36().encode_upper(&mut Uuid::encode_buffer()),
+    ///         "EQVM63WMWPMKFD33IH5DIHG4K"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base36(self.0.as_bytes(), buffer, true)
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base36 = Uuid::nil().base36();
+    /// assert_eq!(*base36.as_uuid(), Uuid::nil());
+    /// ```
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+
+    /// Consumes the [`Base36`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let base36 = Uuid::nil().base36();
+    /// assert_eq!(base36.into_uuid(), Uuid::nil());
+    /// ```
+    pub const fn into_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl fmt::Display for Base36 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($($T:ident<$($a:lifetime),*>),+) => {$(
+        impl<$($a),*> fmt::Display for $T<$($a),*> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(self, f)
+            }
+        }
+
+        impl<$($a),*> fmt::LowerHex for $T<$($a),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
+            }
+        }
+
+        impl<$($a),*> fmt::UpperHex for $T<$($a),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
+            }
+        }
+
+        impl_fmt_from!($T<$($a),*>);
+    )+}
+}
+
+macro_rules! impl_fmt_from {
+    ($T:ident<>) => {
+        impl From<Uuid> for $T {
+            #[inline]
+            fn from(f: Uuid) -> Self {
+                $T(f)
+            }
+        }
+
+        impl From<$T> for Uuid {
+            #[inline]
+            fn from(f: $T) -> Self {
+                f.into_uuid()
+            }
+        }
+
+        impl AsRef<Uuid> for $T {
             #[inline]
             fn as_ref(&self) -> &Uuid {
                 &self.0
@@ -970,9 +1638,26 @@ impl_fmt_traits! {
     Braced<>
 }
 
+impl_fmt_from! {
+    Base32<>
+}
+
+impl_fmt_from! {
+    Base64Url<>
+}
+
+impl_fmt_from! {
+    Base58<>
+}
+
+impl_fmt_from! {
+    Base36<>
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::std::string::ToString;
 
     #[test]
     fn hyphenated_trailing() {
@@ -1038,6 +1723,173 @@ mod tests {
         assert!(buf[len..].iter().all(|x| *x == b'x'));
     }
 
+    #[test]
+    fn base32_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().base32().encode_lower(&mut buf).len();
+        assert_eq!(len, super::Base32::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base32_ref_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().as_base32().encode_lower(&mut buf).len();
+        assert_eq!(len, super::Base32::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base32_bounds() {
+        assert_eq!(
+            Uuid::nil().base32().to_string(),
+            "00000000000000000000000000"
+        );
+        assert_eq!(
+            Uuid::max().base32().to_string(),
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+        );
+        assert_eq!(
+            Uuid::from_u128(1).base32().to_string(),
+            "00000000000000000000000001"
+        );
+        assert_eq!(
+            Uuid::from_u128(1 << 127).base32().to_string(),
+            "40000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn base64url_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().base64url().encode(&mut buf).len();
+        assert_eq!(len, super::Base64Url::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base64url_ref_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().as_base64url().encode(&mut buf).len();
+        assert_eq!(len, super::Base64Url::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base64url_bounds() {
+        assert_eq!(
+            Uuid::nil().base64url().to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAA"
+        );
+        assert_eq!(
+            Uuid::max().base64url().to_string(),
+            "_____________________w"
+        );
+        assert_eq!(
+            Uuid::from_u128(1).base64url().to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAQ"
+        );
+        assert_eq!(
+            Uuid::from_u128(0x936da01f9abd4d9d80c702af85c822a8)
+                .base64url()
+                .to_string(),
+            "k22gH5q9TZ2AxwKvhcgiqA"
+        );
+    }
+
+    #[test]
+    fn base58_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().base58().encode(&mut buf).len();
+        assert_eq!(len, super::Base58::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base58_ref_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().as_base58().encode(&mut buf).len();
+        assert_eq!(len, super::Base58::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base58_bounds() {
+        assert_eq!(
+            Uuid::nil().base58().to_string(),
+            "1111111111111111111111"
+        );
+        assert_eq!(
+            Uuid::max().base58().to_string(),
+            "YcVfxkQb6JRzqk5kF2tNLv"
+        );
+        assert_eq!(
+            Uuid::from_u128(0x936da01f9abd4d9d80c702af85c822a8)
+                .base58()
+                .to_string(),
+            "KCtqn2CDK64hXvWfYwhRnP"
+        );
+    }
+
+    #[test]
+    fn base36_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().base36().encode_lower(&mut buf).len();
+        assert_eq!(len, super::Base36::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base36_ref_trailing() {
+        let mut buf = [b'x'; 100];
+        let len = Uuid::nil().as_base36().encode_upper(&mut buf).len();
+        assert_eq!(len, super::Base36::LENGTH);
+        assert!(buf[len..].iter().all(|x| *x == b'x'));
+    }
+
+    #[test]
+    fn base36_bounds() {
+        assert_eq!(
+            Uuid::nil().base36().to_string(),
+            "0000000000000000000000000"
+        );
+        assert_eq!(
+            Uuid::max().base36().to_string(),
+            "f5lxx1zz5pnorynqglhzmsp33"
+        );
+        assert_eq!(
+            Uuid::from_u128(0x936da01f9abd4d9d80c702af85c822a8)
+                .base36()
+                .to_string(),
+            "8q7mlcgv6pekrk0havp5wkcyw"
+        );
+    }
+
+    #[test]
+    fn base58_base36_sort_order() {
+        let mut uuids = [
+            Uuid::max(),
+            Uuid::from_u128(57),
+            Uuid::from_u128(58),
+            Uuid::from_u128(0x936da01f9abd4d9d80c702af85c822a8),
+            Uuid::nil(),
+            Uuid::from_u128(1 << 127),
+            Uuid::from_u128(35),
+            Uuid::from_u128(36),
+            Uuid::from_u128((1 << 127) - 1),
+        ];
+        uuids.sort();
+
+        for pair in uuids.windows(2) {
+            assert!(pair[0].base58().to_string() < pair[1].base58().to_string());
+            assert!(pair[0].base36().to_string() < pair[1].base36().to_string());
+            assert!(
+                pair[0].base36().encode_upper(&mut Uuid::encode_buffer())
+                    < pair[1].base36().encode_upper(&mut Uuid::encode_buffer())
+            );
+        }
+    }
+
     #[test]
     #[should_panic]
     fn hyphenated_too_small() {
@@ -1085,4 +1937,52 @@ mod tests {
         let braced = Uuid::nil().braced();
         assert_eq!(Uuid::from(braced), Uuid::nil());
     }
+
+    #[test]
+    #[should_panic]
+    fn base32_too_small() {
+        Uuid::nil().base32().encode_lower(&mut [0; 25]);
+    }
+
+    #[test]
+    fn base32_to_inner() {
+        let base32 = Uuid::nil().base32();
+        assert_eq!(Uuid::from(base32), Uuid::nil());
+    }
+
+    #[test]
+    #[should_panic]
+    fn base64url_too_small() {
+        Uuid::nil().base64url().encode(&mut [0; 21]);
+    }
+
+    #[test]
+    fn base64url_to_inner() {
+        let base64url = Uuid::nil().base64url();
+        assert_eq!(Uuid::from(base64url), Uuid::nil());
+    }
+
+    #[test]
+    #[should_panic]
+    fn base58_too_small() {
+        Uuid::nil().base58().encode(&mut [0; 21]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn base36_too_small() {
+        Uuid::nil().base36().encode_lower(&mut [0; 24]);
+    }
+
+    #[test]
+    fn base58_to_inner() {
+        let base58 = Uuid::nil().base58();
+        assert_eq!(Uuid::from(base58), Uuid::nil());
+    }
+
+    #[test]
+    fn base36_to_inner() {
+        let base36 = Uuid::nil().base36();
+        assert_eq!(Uuid::from(base36), Uuid::nil());
+    }
 }
@@ -33,20 +33,54 @@
 //!
 //! * `v1` - adds the [`Uuid::new_v1`] function and the ability to create a V1
 //!   using an implementation of [`v1::ClockSequence`] (usually
-//! [`v1::Context`]) and a timestamp from `time::timespec`.
+//!   [`v1::Context`]) and a timestamp from `time::timespec`. This also adds
+//!   the [`node::NodeId`] type, which can look up a node ID for V1, V2, and
+//!   V6 UUIDs from the system's network interfaces, and a
+//!   [`v1::StatefulContext`] that keeps its clock sequence in stable storage,
+//!   and a [`v1::TickContext`] that reports when a tick runs out of clock
+//!   sequences.
+//! * `v2` - adds the [`Uuid::new_v2`] function and the ability to create a V2
+//!   DCE Security UUID from a [`v2::Domain`], a local identifier, and the
+//!   same [`v1::Timestamp`] as V1.
 //! * `v3` - adds the [`Uuid::new_v3`] function and the ability to create a V3
 //!   UUID based on the MD5 hash of some data.
 //! * `v4` - adds the [`Uuid::new_v4`] function and the ability to randomly
 //!   generate a UUID.
 //! * `v5` - adds the [`Uuid::new_v5`] function and the ability to create a V5
 //!   UUID based on the SHA1 hash of some data.
+//! * `v6` - adds the [`Uuid::new_v6`] function and the ability to create a V6
+//!   UUID using the same [`v1::Timestamp`] and [`v1::ClockSequence`] as V1,
+//!   and to convert between V1 and V6 UUIDs.
+//! * `v7` - adds the [`Uuid::new_v7`] function and the ability to create a V7
+//!   UUID from a Unix timestamp in milliseconds and some random data, or
+//!   using a [`v7::Context`] to keep UUIDs strictly ordered.
+//! * `v8` - adds the [`Uuid::new_v8`] function and the ability to create a V8
+//!   UUID using custom data, and to read that data back.
+//! * `v8-sha2` - adds the [`Uuid::new_v8_sha256`] and [`Uuid::new_v8_sha512`]
+//!   functions for generating name-based V8 UUIDs using SHA-256 and SHA-512.
 //! * `macros` - adds the `uuid!` macro that can parse UUIDs at compile time.
 //! * `serde` - adds the ability to serialize and deserialize a UUID using the
 //!   `serde` crate.
 //! * `arbitrary` - adds an `Arbitrary` trait implementation to `Uuid`.
-//! * `fast-rng` - when combined with `v4` uses a faster algorithm for
+//! * `rand_core` - adds functions like [`Uuid::new_v4_with`] that create
+//!   UUIDs using any random number generator implementing `rand_core::RngCore`.
+//! * `testing` - adds the [`testing`] module with a [`testing::SeededGenerator`]
+//!   that creates reproducible UUIDs for tests, and a way to make
+//!   [`Uuid::new_v4`] use one on the current thread.
+//! * `chrono` - adds conversions between [`timestamp::Timestamp`] and
+//!   `chrono::DateTime`, for use with [`Uuid::new_v1_at`] and
+//!   [`Uuid::new_v7_at`].
+//! * `time` - adds conversions between [`timestamp::Timestamp`] and
+//!   `time::OffsetDateTime`, for use with [`Uuid::new_v1_at`] and
+//!   [`Uuid::new_v7_at`].
+//! * `fast-rng` - when combined with `v4` or `v7` uses a faster algorithm for
 //!   generating random UUIDs. This feature requires more dependencies to
 //!   compile, but is just as suitable for UUIDs as the default algorithm.
+//! * `thread-local-rng` - when combined with `v4` or `v7` keeps a
+//!   cryptographically secure generator per thread, seeded and periodically
+//!   reseeded from the operating system. This avoids a system call per UUID,
+//!   and takes precedence over `fast-rng`. On Linux, the generator is also
+//!   reseeded in a child process after a `fork`.
 //!
 //! By default, `uuid` can be depended on with:
 //!
@@ -160,11 +194,29 @@
 //! [`cargo-web`]: https://crates.io/crates/cargo-web
 //! [`Uuid`]: struct.Uuid.html
 //! [`Uuid::new_v1`]: struct.Uuid.html#method.new_v1
+//! [`Uuid::new_v1_at`]: struct.Uuid.html#method.new_v1_at
+//! [`Uuid::new_v2`]: struct.Uuid.html#method.new_v2
 //! [`Uuid::new_v3`]: struct.Uuid.html#method.new_v3
 //! [`Uuid::new_v4`]: struct.Uuid.html#method.new_v4
+//! [`Uuid::new_v4_with`]: struct.Uuid.html#method.new_v4_with
 //! [`Uuid::new_v5`]: struct.Uuid.html#method.new_v5
-//! [`v1::ClockSequence`]: v1/trait.ClockSequence.html
+//! [`Uuid::new_v6`]: struct.Uuid.html#method.new_v6
+//! [`Uuid::new_v7`]: struct.Uuid.html#method.new_v7
+//! [`Uuid::new_v7_at`]: struct.Uuid.html#method.new_v7_at
+//! [`Uuid::new_v8`]: struct.Uuid.html#method.new_v8
+//! [`Uuid::new_v8_sha256`]: struct.Uuid.html#method.new_v8_sha256
+//! [`Uuid::new_v8_sha512`]: struct.Uuid.html#method.new_v8_sha512
+//! [`timestamp::Timestamp`]: timestamp/struct.Timestamp.html
+//! [`v1::ClockSequence`]: timestamp/trait.ClockSequence.html
+//! [`v1::Timestamp`]: timestamp/struct.Timestamp.html
+//! [`node::NodeId`]: node/struct.NodeId.html
+//! [`testing`]: testing/index.html
+//! [`testing::SeededGenerator`]: testing/struct.SeededGenerator.html
 //! [`v1::Context`]: v1/struct.Context.html
+//! [`v1::StatefulContext`]: v1/struct.StatefulContext.html
+//! [`v1::TickContext`]: v1/struct.TickContext.html
+//! [`v2::Domain`]: v2/enum.Domain.html
+//! [`v7::Context`]: v7/struct.Context.html
 //! [`getrandom`'s docs]: https://docs.rs/getrandom
 
 #![no_std]
@@ -195,18 +247,32 @@ mod error;
 mod parser;
 
 pub mod fmt;
+pub mod scan;
 
+pub mod timestamp;
+#[cfg(feature = "v1")]
+pub mod node;
 #[cfg(feature = "v1")]
 pub mod v1;
+#[cfg(feature = "v2")]
+pub mod v2;
 #[cfg(feature = "v3")]
 mod v3;
 #[cfg(feature = "v4")]
 mod v4;
 #[cfg(feature = "v5")]
 mod v5;
+#[cfg(feature = "v6")]
+mod v6;
+#[cfg(feature = "v7")]
+pub mod v7;
+#[cfg(feature = "v8")]
+mod v8;
 
 #[cfg(feature = "rng")]
 mod rng;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 mod external;
 
@@ -218,7 +284,11 @@ pub extern crate uuid_macro;
 
 use crate::std::convert;
 
-pub use crate::{builder::Builder, error::Error};
+pub use crate::{
+    builder::Builder,
+    error::{Error, ErrorKind},
+    parser::CanonicalParser,
+};
 
 /// A 128-bit (16 byte) buffer containing the ID.
 pub type Bytes = [u8; 16];
@@ -239,6 +309,14 @@ pub enum Version {
     Random,
     /// Version 5: SHA-1 hash.
     Sha1,
+    /// Version 6: Reordered Gregorian time.
+    SortMac,
+    /// Version 7: Unix epoch time-ordered.
+    SortRand,
+    /// Version 8: Custom.
+    Custom,
+    /// Special case for `max` UUID.
+    Max = 0xff,
 }
 
 /// The reserved variants of UUIDs.
@@ -294,6 +372,10 @@ pub enum Variant {
 ///   `a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8`.
 /// * [`urn`](#method.to_urn): `urn:uuid:A1A2A3A4-B1B2-C1C2-D1D2-D3D4D5D6D7D8`.
 /// * [`braced`](#method.braced): `{a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8}`.
+/// * [`base32`](#method.base32): `51MAHT9CDJR71D3MPKTKAXDNYR`.
+/// * [`base64url`](#method.base64url): `oaKjpLGywcLR0tPU1dbX2A`.
+/// * [`base58`](#method.base58): `LxeUnqNwLWQUv2JDtUgQij`.
+/// * [`base36`](#method.base36): `9khonz1x9jay5esoudhd7p048`.
 ///
 /// The default representation when formatting a UUID with `Display` is
 /// hyphenated:
@@ -479,6 +561,10 @@ impl Uuid {
             3 => Some(Version::Md5),
             4 => Some(Version::Random),
             5 => Some(Version::Sha1),
+            6 => Some(Version::SortMac),
+            7 => Some(Version::SortRand),
+            8 => Some(Version::Custom),
+            0xf if self.is_max() => Some(Version::Max),
             _ => None,
         }
     }
@@ -700,6 +786,11 @@ impl Uuid {
         self.as_u128() == 0
     }
 
+    /// Tests if the UUID is max (all ones).
+    pub const fn is_max(&self) -> bool {
+        self.as_u128() == u128::MAX
+    }
+
     /// A buffer that can be used for `encode_...` calls, that is
     /// guaranteed to be long enough for any of the format adapters.
     ///
@@ -752,7 +843,7 @@ pub mod serde {
     //! to change the way a [`Uuid`](../struct.Uuid.html) is serialized
     //! and deserialized.
 
-    pub use crate::external::serde_support::compact;
+    pub use crate::external::serde_support::{base64url, compact};
 }
 
 #[cfg(test)]
@@ -890,6 +981,27 @@ mod tests {
         assert_eq!(not_nil.get_version(), Some(Version::Random))
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_max() {
+        let max = Uuid::max();
+        let not_max = new();
+        let almost_max = Uuid::from_u128(u128::MAX - 1);
+
+        assert!(max.is_max());
+        assert!(!not_max.is_max());
+        assert!(!almost_max.is_max());
+        assert!(!max.is_nil());
+
+        assert_eq!(max.get_version(), Some(Version::Max));
+        assert_eq!(max.get_version_num(), 0xf);
+        assert_eq!(almost_max.get_version(), None);
+        assert_eq!(Builder::max().into_uuid(), max);
+
+        assert!(not_max < max);
+        assert!(Uuid::nil() < max);
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_predefined_namespaces() {
@@ -17,6 +17,17 @@
 /// pub const SCHEMA_ATTR_NAME: Uuid = uuid!("00000000-0000-0000-0000-ffff00000002");
 /// ```
 ///
+/// The nil and max UUIDs can be used as constant bounds:
+///
+/// ```
+/// # use uuid::{uuid, Uuid};
+/// const MIN: Uuid = uuid!("00000000-0000-0000-0000-000000000000");
+/// const MAX: Uuid = uuid!("ffffffff-ffff-ffff-ffff-ffffffffffff");
+///
+/// assert!(MIN.is_nil());
+/// assert!(MAX.is_max());
+/// ```
+///
 /// Defining a local variable:
 ///
 /// ```
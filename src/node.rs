@@ -0,0 +1,312 @@
+//! Node IDs for time-based UUIDs.
+//!
+//! Version 1, 2, and 6 UUIDs end with a 48-bit node ID that's meant to be
+//! unique to the machine creating them. RFC 9562 suggests using the MAC
+//! address of a network interface, or a random value with the multicast bit
+//! set so it can never collide with a real MAC address.
+//!
+//! Note that you need to enable the `v1` Cargo feature
+//! in order to use this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use uuid::{
+//!     node::NodeId,
+//!     v1::{Context, Timestamp},
+//!     Uuid,
+//! };
+//!
+//! let node = NodeId::from_bytes([1, 2, 3, 4, 5, 6]);
+//! let context = Context::new(42);
+//!
+//! let uuid = Uuid::new_v1(Timestamp::from_unix(&context, 1_497_624_119, 1234), &node);
+//!
+//! assert_eq!(&[1, 2, 3, 4, 5, 6], &uuid.as_bytes()[10..]);
+//! ```
+//!
+//! # References
+//!
+//! * [Node IDs in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-6.10)
+
+use crate::std::ops::Deref;
+
+/// A 48-bit node ID for V1, V2, and V6 UUIDs.
+///
+/// A `NodeId` dereferences to `[u8; 6]`, so `&node` can be passed anywhere a
+/// `&[u8; 6]` node ID is expected, like [`Uuid::new_v1`] and
+/// [`Uuid::new_v6`].
+///
+/// [`Uuid::new_v1`]: ../struct.Uuid.html#method.new_v1
+/// [`Uuid::new_v6`]: ../struct.Uuid.html#method.new_v6
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId([u8; 6]);
+
+impl NodeId {
+    /// Creates a node ID from its bytes, such as a MAC address.
+    pub const fn from_bytes(bytes: [u8; 6]) -> Self {
+        NodeId(bytes)
+    }
+
+    /// Creates a random node ID.
+    ///
+    /// The multicast bit, the least significant bit of the first byte, is
+    /// set so the node ID can't collide with the MAC address of a network
+    /// interface, as RFC 9562 requires.
+    ///
+    /// This method requires either the `rng` or `fast-rng` feature to also be
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::node::NodeId;
+    /// let node = NodeId::random();
+    ///
+    /// assert!(node.is_random());
+    /// ```
+    #[cfg(feature = "rng")]
+    pub fn random() -> Self {
+        let mut bytes = [0; 6];
+        crate::rng::fill(&mut bytes);
+
+        NodeId::from_random_bytes(bytes)
+    }
+
+    /// Returns the node ID of the current machine.
+    ///
+    /// On Linux, this is the MAC address of the first network interface,
+    /// ordered by name, listed under `/sys/class/net` that isn't a loopback
+    /// interface. If there isn't one, or on other platforms, this falls back
+    /// to [`NodeId::random`].
+    ///
+    /// This reads the filesystem on every call, so the result should be kept
+    /// and reused rather than looked up for each UUID.
+    ///
+    /// This method requires either the `rng` or `fast-rng` feature, and the
+    /// `std` feature, to also be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{node::NodeId, v1::{Context, Timestamp}, Uuid};
+    /// let node = NodeId::from_system();
+    /// let context = Context::new_random();
+    ///
+    /// let uuid = Uuid::new_v1(Timestamp::now(&context), &node);
+    /// ```
+    ///
+    /// [`NodeId::random`]: #method.random
+    #[cfg(all(feature = "std", feature = "rng"))]
+    pub fn from_system() -> Self {
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(node) =
+                NodeId::from_sys_class_net("/sys/class/net".as_ref())
+            {
+                return node;
+            }
+        }
+
+        NodeId::random()
+    }
+
+    /// Returns the bytes of the node ID.
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Returns whether the multicast bit is set, which means the node ID
+    /// wasn't taken from a network interface.
+    pub const fn is_random(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    #[cfg(feature = "rng")]
+    const fn from_random_bytes(mut bytes: [u8; 6]) -> Self {
+        bytes[0] |= 0x01;
+
+        NodeId(bytes)
+    }
+
+    #[cfg(all(feature = "std", feature = "rng", target_os = "linux"))]
+    fn from_sys_class_net(dir: &crate::std::path::Path) -> Option<Self> {
+        use crate::std::{fs, vec::Vec};
+
+        let mut interfaces = fs::read_dir(dir)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name() != "lo")
+            .map(|entry| entry.path())
+            .collect::<Vec<_>>();
+
+        interfaces.sort();
+
+        interfaces.iter().find_map(|interface| {
+            let address = fs::read_to_string(interface.join("address")).ok()?;
+            let node = parse_mac(address.trim())?;
+
+            // Loopback interfaces under other names have an all-zero address,
+            // and a multicast address would be mistaken for a random node
+            if node.0 == [0; 6] || node.is_random() {
+                None
+            } else {
+                Some(node)
+            }
+        })
+    }
+}
+
+/// Parses a MAC address written as six colon-separated pairs of hex digits.
+#[cfg(all(feature = "std", feature = "rng", target_os = "linux"))]
+fn parse_mac(s: &str) -> Option<NodeId> {
+    let mut bytes = [0; 6];
+    let mut parts = s.split(':');
+
+    for byte in bytes.iter_mut() {
+        let part = parts.next()?;
+        if part.len() != 2 || !part.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        *byte = u8::from_str_radix(part, 16).ok()?;
+    }
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(NodeId(bytes))
+}
+
+impl Deref for NodeId {
+    type Target = [u8; 6];
+
+    fn deref(&self) -> &[u8; 6] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for NodeId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 6]> for NodeId {
+    fn from(bytes: [u8; 6]) -> Self {
+        NodeId(bytes)
+    }
+}
+
+impl From<NodeId> for [u8; 6] {
+    fn from(node: NodeId) -> Self {
+        node.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use crate::{v1::Timestamp, Uuid};
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_v1() {
+        let node = NodeId::from_bytes([1, 2, 3, 4, 5, 6]);
+        let ts = Timestamp::from_rfc4122(1_497_624_119, 0);
+
+        assert_eq!(
+            Uuid::new_v1(ts, &node),
+            Uuid::new_v1(ts, &[1, 2, 3, 4, 5, 6])
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_is_random() {
+        assert!(!NodeId::from_bytes([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e])
+            .is_random());
+        assert!(NodeId::from_bytes([0x01, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e])
+            .is_random());
+    }
+
+    #[test]
+    #[cfg(feature = "rng")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_random() {
+        let node = NodeId::random();
+
+        assert!(node.is_random());
+        assert_ne!(node, NodeId::random());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_from_system() {
+        let node = NodeId::from_system();
+
+        assert_ne!(*node.as_bytes(), [0; 6]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng", target_os = "linux"))]
+    fn test_parse_mac() {
+        assert_eq!(
+            parse_mac("00:1a:2B:3c:4d:5e"),
+            Some(NodeId([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]))
+        );
+
+        assert_eq!(parse_mac(""), None);
+        assert_eq!(parse_mac("00:1a:2b:3c:4d"), None);
+        assert_eq!(parse_mac("00:1a:2b:3c:4d:5e:6f"), None);
+        assert_eq!(parse_mac("00:1a:2b:3c:4d:5"), None);
+        assert_eq!(parse_mac("00:1a:2b:3c:4d:5g"), None);
+        assert_eq!(parse_mac("+0:1a:2b:3c:4d:5e"), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng", target_os = "linux"))]
+    fn test_from_sys_class_net() {
+        use crate::std::{env, fs, process};
+
+        let dir = env::temp_dir().join(crate::std::format!(
+            "uuid-test-sys-class-net-{}",
+            process::id()
+        ));
+
+        let interface = |name: &str, address: &str| {
+            fs::create_dir_all(dir.join(name)).unwrap();
+            fs::write(dir.join(name).join("address"), address).unwrap();
+        };
+
+        interface("lo", "00:00:00:00:00:00\n");
+        interface("dummy0", "00:00:00:00:00:00\n");
+        interface("eth1", "02:00:00:00:00:02\n");
+        interface("eth0", "02:00:00:00:00:01\n");
+        interface("bond0", "03:00:00:00:00:00\n");
+        interface(
+            "ib0",
+            "00:00:00:00:fe:80:00:00:00:00:00:00:00:00:00:00:00:00:00:00\n",
+        );
+
+        let node = NodeId::from_sys_class_net(&dir);
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(node, Some(NodeId([0x02, 0, 0, 0, 0, 0x01])));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng", target_os = "linux"))]
+    fn test_from_sys_class_net_missing() {
+        assert_eq!(
+            NodeId::from_sys_class_net("/nonexistent/sys/class/net".as_ref()),
+            None
+        );
+    }
+}
@@ -16,12 +16,12 @@
 use crate::{
     error::*,
     std::{convert::TryFrom, str},
-    Uuid,
+    Uuid, Variant, Version,
 };
 
 #[rustfmt::skip]
 #[path = "../shared/parser.rs"]
-mod imp;
+pub(crate) mod imp;
 
 impl str::FromStr for Uuid {
     type Err = Error;
@@ -97,12 +97,664 @@ impl Uuid {
             Err(_) => Err(Error(ErrorKind::Other)),
         }
     }
+
+    /// Parses a `Uuid` from a slice of ASCII bytes, in any of the formats
+    /// supported by [`parse_str`].
+    ///
+    /// This avoids validating the input as UTF-8 first, which is wasted work
+    /// since parsing only succeeds for ASCII input anyway. Errors include the
+    /// same details as [`parse_str`], and invalid UTF-8 in the input is
+    /// reported with the value of the offending byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ErrorKind, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_ascii(b"67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?);
+    ///
+    /// let err = Uuid::parse_ascii(b"67e55044-10b1-426f-9247-bb680e5fe0\xff8").unwrap_err();
+    ///
+    /// assert_eq!(&ErrorKind::Byte { byte: 0xff, index: 35 }, err.kind());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    pub fn parse_ascii(input: &[u8]) -> Result<Uuid, Error> {
+        imp::try_parse_ascii(input)
+            .map(Uuid::from_bytes)
+            .map_err(InvalidUuid::into_err)
+    }
+
+    /// Parses a `Uuid` from a slice of ASCII bytes, in any of the formats
+    /// supported by [`parse_str`].
+    ///
+    /// This is the byte-oriented version of [`try_parse`], and can be used
+    /// in `const` contexts. Like `try_parse`, it doesn't generate useful
+    /// error messages; use [`parse_ascii`] for detailed diagnostics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// const UUID: Uuid = match Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5fe0c8") {
+    ///     Ok(uuid) => uuid,
+    ///     Err(_) => panic!("invalid UUID"),
+    /// };
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", UUID.hyphenated().to_string());
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`try_parse`]: #method.try_parse
+    /// [`parse_ascii`]: #method.parse_ascii
+    #[inline]
+    pub const fn try_parse_ascii(input: &[u8]) -> Result<Uuid, Error> {
+        match imp::try_parse_ascii(input) {
+            Ok(bytes) => Ok(Uuid::from_bytes(bytes)),
+            Err(_) => Err(Error(ErrorKind::Other)),
+        }
+    }
+
+    /// Parses a `Uuid` from messy input, such as a value copied from a
+    /// spreadsheet or received from a loosely formatted feed.
+    ///
+    /// Compared to [`parse_str`], this also accepts:
+    ///
+    /// * Whitespace around the UUID.
+    /// * Any number of matching quotes (`"` or `'`), parentheses, braces, or
+    ///   square brackets around the UUID.
+    /// * Either a `urn:uuid:` or a `0x` prefix, but not both, in any case.
+    /// * Hyphens in any position, including none at all, as long as there
+    ///   are exactly 32 hexadecimal digits.
+    ///
+    /// Errors report positions in the original `input`, so the diagnostics
+    /// are as precise as the ones from [`parse_str`]. Input with the wrong
+    /// number of digits fails with [`ErrorKind::DigitCount`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_lenient("  URN:UUID:67E55044-10B1-426F-9247-BB680E5FE0C8\n")?);
+    /// assert_eq!(uuid, Uuid::parse_lenient("\"{67e55044-10b1-426f-9247-bb680e5fe0c8}\"")?);
+    /// assert_eq!(uuid, Uuid::parse_lenient("(0x67e5504410b1426f9247bb680e5fe0c8)")?);
+    /// assert_eq!(uuid, Uuid::parse_lenient("67e55044-10b1-426f-9247bb680e5fe0c8")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Invalid characters are still reported at their position in the input:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let err = Uuid::parse_lenient(" '67e55044-10b1-426f-9247-bb680e5fe0cz' ").unwrap_err();
+    ///
+    /// assert_eq!(Some('z'), err.invalid_char());
+    /// assert_eq!(Some(38), err.index());
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`ErrorKind::DigitCount`]: enum.ErrorKind.html#variant.DigitCount
+    pub fn parse_lenient(input: &str) -> Result<Uuid, Error> {
+        let mut s = input.trim();
+
+        while let [b'"', .., b'"']
+        | [b'\'', .., b'\'']
+        | [b'(', .., b')']
+        | [b'{', .., b'}']
+        | [b'[', .., b']'] = s.as_bytes()
+        {
+            s = s[1..s.len() - 1].trim();
+        }
+
+        for prefix in ["urn:uuid:", "0x"].iter() {
+            if s.get(..prefix.len())
+                .map_or(false, |p| p.eq_ignore_ascii_case(prefix))
+            {
+                s = &s[prefix.len()..];
+                break;
+            }
+        }
+
+        // The position of what's left within the original input, so errors
+        // point at the right place
+        let offset = s.as_ptr() as usize - input.as_ptr() as usize;
+
+        let mut bytes = [0; 16];
+        let mut digits = 0;
+
+        for (index, character) in s.char_indices() {
+            let digit = match character {
+                '-' => continue,
+                _ => character.to_digit(16).ok_or(Error(ErrorKind::Char {
+                    character,
+                    index: offset + index + 1,
+                }))?,
+            };
+
+            if digits < 32 {
+                bytes[digits / 2] |= (digit as u8) << (4 * (1 - digits % 2));
+            }
+
+            digits += 1;
+        }
+
+        if digits != 32 {
+            return Err(Error(ErrorKind::DigitCount { count: digits }));
+        }
+
+        Ok(Uuid::from_bytes(bytes))
+    }
+
+    /// Parses a `Uuid` from its canonical string form only.
+    ///
+    /// [`parse_str`] accepts several encodings in any case, so the same UUID
+    /// can be written many different ways. This function only accepts the
+    /// lowercase hyphenated form that [`hyphenated`] produces, so there is
+    /// exactly one string for each UUID. That makes it suitable for inputs
+    /// that are compared or hashed as text, like cache keys or signed
+    /// payloads.
+    ///
+    /// To also check the variant or version of the parsed UUID, use a
+    /// [`CanonicalParser`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ErrorKind, Uuid};
+    /// let uuid = Uuid::parse_canonical("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///
+    /// assert!(uuid.is_ok());
+    ///
+    /// let err = Uuid::parse_canonical("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap_err();
+    ///
+    /// assert_eq!(&ErrorKind::NonCanonical { index: 3 }, err.kind());
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`hyphenated`]: #method.hyphenated
+    /// [`CanonicalParser`]: struct.CanonicalParser.html
+    #[inline]
+    pub const fn parse_canonical(input: &str) -> Result<Uuid, Error> {
+        CanonicalParser::new().parse(input)
+    }
+}
+
+impl Uuid {
+    /// Parses a `Uuid` from a Crockford Base32 string, like the ones written
+    /// by [`base32`].
+    ///
+    /// The input must be exactly 26 characters long, and is the same as the
+    /// text form of a [ULID]. Letters may be in any case, and the letters
+    /// `I` and `L` are read as `1` and `O` as `0`, so ids copied by hand
+    /// still parse. Hyphens aren't allowed.
+    ///
+    /// This function can be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_base32("01ARZ3NDEKTSV4RRFFQ69G5FAV")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_str("01563e3a-b5d3-d676-4c61-efb99302bd5b")?);
+    /// assert_eq!(uuid, Uuid::parse_base32("olarz3ndektsv4rrffq69g5fav")?);
+    /// assert_eq!("01ARZ3NDEKTSV4RRFFQ69G5FAV", uuid.base32().to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`base32`]: #method.base32
+    /// [ULID]: https://github.com/ulid/spec
+    pub const fn parse_base32(input: &str) -> Result<Uuid, Error> {
+        let s = input.as_bytes();
+        let mut value: u128 = 0;
+        let mut i = 0;
+
+        while i < s.len() {
+            let digit = BASE32_TABLE[s[i] as usize];
+            if digit == 0xff {
+                return Err(invalid_digit(s, i));
+            }
+
+            value = (value << 5) | digit as u128;
+            i += 1;
+        }
+
+        if s.len() != 26 {
+            return Err(Error(ErrorKind::EncodedLength {
+                expected: 26,
+                len: s.len(),
+            }));
+        }
+
+        // 26 digits hold 130 bits, so the first can only use its lower 3
+        if BASE32_TABLE[s[0] as usize] > 7 {
+            return Err(Error(ErrorKind::EncodedOverflow));
+        }
+
+        Ok(Uuid::from_u128(value))
+    }
+
+    /// Parses a `Uuid` from an unpadded, URL-safe Base64 string, like the
+    /// ones written by [`base64url`].
+    ///
+    /// The input must be exactly 22 characters from the `base64url` alphabet
+    /// of RFC 4648, without `=` padding. Since the last character holds only
+    /// 2 bits of the UUID, its other 4 bits must be zero so that every UUID
+    /// has exactly one encoding.
+    ///
+    /// This function can be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_base64url("-RaMXs6yT6q2vzKb85-h5A")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?);
+    /// assert_eq!("-RaMXs6yT6q2vzKb85-h5A", uuid.base64url().to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`base64url`]: #method.base64url
+    pub const fn parse_base64url(input: &str) -> Result<Uuid, Error> {
+        let s = input.as_bytes();
+        let mut value: u128 = 0;
+        let mut last = 0;
+        let mut i = 0;
+
+        while i < s.len() {
+            let digit = BASE64URL_TABLE[s[i] as usize];
+            if digit == 0xff {
+                return Err(invalid_digit(s, i));
+            }
+
+            if i < 21 {
+                value = (value << 6) | digit as u128;
+            } else {
+                last = digit;
+            }
+            i += 1;
+        }
+
+        if s.len() != 22 {
+            return Err(Error(ErrorKind::EncodedLength {
+                expected: 22,
+                len: s.len(),
+            }));
+        }
+
+        // The last digit only holds the lowest 2 bits, so its padding must
+        // be zero
+        if last & 0x0f != 0 {
+            return Err(Error(ErrorKind::EncodedOverflow));
+        }
+
+        Ok(Uuid::from_u128((value << 2) | (last >> 4) as u128))
+    }
+}
+
+impl Uuid {
+    /// Parses a `Uuid` from a Base58 string in the Bitcoin alphabet, like the
+    /// ones written by [`base58`].
+    ///
+    /// The input must be exactly 22 characters long, including any padding
+    /// with the zero digit `1`.
+    ///
+    /// This function can be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ErrorKind, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_base58("XkzTFVH2jeQ8TxAEFtaPkw")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?);
+    ///
+    /// let err = Uuid::parse_base58("XkzTFVH2jeQ8TxAEFtaPk0").unwrap_err();
+    ///
+    /// assert_eq!(Some('0'), err.invalid_char());
+    /// assert_eq!(Some(22), err.index());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`base58`]: #method.base58
+    pub const fn parse_base58(input: &str) -> Result<Uuid, Error> {
+        match decode_base(input, &BASE58_TABLE, 58, 22) {
+            Ok(value) => Ok(Uuid::from_u128(value)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses a `Uuid` from a Base36 string, like the ones written by
+    /// [`base36`].
+    ///
+    /// The input must be exactly 25 characters long, including any padding
+    /// with the zero digit `0`. Letters may be in any case.
+    ///
+    /// This function can be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_base36("eqvm63wmwpmkfd33ih5dihg4k")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4")?);
+    /// assert_eq!(uuid, Uuid::parse_base36("EQVM63WMWPMKFD33IH5DIHG4K")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`base36`]: #method.base36
+    pub const fn parse_base36(input: &str) -> Result<Uuid, Error> {
+        match decode_base(input, &BASE36_TABLE, 36, 25) {
+            Ok(value) => Ok(Uuid::from_u128(value)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Decodes a fixed-width number of `len` digits in `base`, using `table` to
+/// map characters to digits.
+const fn decode_base(
+    input: &str,
+    table: &[u8; 256],
+    base: u128,
+    len: usize,
+) -> Result<u128, Error> {
+    let s = input.as_bytes();
+    let mut value: u128 = 0;
+    let mut overflow = false;
+    let mut i = 0;
+
+    while i < s.len() {
+        let digit = table[s[i] as usize];
+        if digit == 0xff {
+            return Err(invalid_digit(s, i));
+        }
+
+        // Once the value overflows it's only decoded to finish checking the
+        // characters, so wrapping is fine
+        if value > (u128::MAX - digit as u128) / base {
+            overflow = true;
+        }
+        value = value.wrapping_mul(base).wrapping_add(digit as u128);
+        i += 1;
+    }
+
+    if s.len() != len {
+        return Err(Error(ErrorKind::EncodedLength {
+            expected: len,
+            len: s.len(),
+        }));
+    }
+
+    if overflow {
+        return Err(Error(ErrorKind::EncodedOverflow));
+    }
+
+    Ok(value)
+}
+
+/// Decodes Base58 digits in the Bitcoin alphabet, with `0xff` for characters
+/// outside the alphabet.
+const BASE58_TABLE: [u8; 256] = {
+    let alphabet =
+        b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    let mut buf = [0xff; 256];
+    let mut i = 0;
+
+    while i < alphabet.len() {
+        buf[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    buf
+};
+
+/// Decodes Base36 digits in any case, with `0xff` for characters outside the
+/// alphabet.
+const BASE36_TABLE: [u8; 256] = {
+    let alphabet = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut buf = [0xff; 256];
+    let mut i = 0;
+
+    while i < alphabet.len() {
+        buf[alphabet[i] as usize] = i as u8;
+        // Setting 0x20 lowercases letters and leaves digits as they are
+        buf[(alphabet[i] | 0x20) as usize] = i as u8;
+        i += 1;
+    }
+
+    buf
+};
+
+/// Decodes `base64url` digits, with `0xff` for characters outside the
+/// alphabet.
+const BASE64URL_TABLE: &[u8; 256] = &{
+    let alphabet =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut buf = [0xff; 256];
+    let mut i = 0;
+
+    while i < alphabet.len() {
+        buf[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    buf
+};
+
+/// Decodes Crockford Base32 digits, with `0xff` for characters outside the
+/// alphabet.
+const BASE32_TABLE: &[u8; 256] = &{
+    let alphabet = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    let mut buf = [0xff; 256];
+    let mut i = 0;
+
+    while i < alphabet.len() {
+        buf[alphabet[i] as usize] = i as u8;
+        // Setting 0x20 lowercases letters and leaves digits as they are
+        buf[(alphabet[i] | 0x20) as usize] = i as u8;
+        i += 1;
+    }
+
+    // Crockford's confusable letters
+    buf[b'I' as usize] = 1;
+    buf[b'i' as usize] = 1;
+    buf[b'L' as usize] = 1;
+    buf[b'l' as usize] = 1;
+    buf[b'O' as usize] = 0;
+    buf[b'o' as usize] = 0;
+
+    buf
+};
+
+/// The error for the invalid digit at byte `i` of an encoded string.
+///
+/// Every alphabet is ASCII, so a non-ASCII digit is reported as its first
+/// byte rather than decoded as a character.
+const fn invalid_digit(s: &[u8], i: usize) -> Error {
+    match s[i] {
+        byte @ 0x00..=0x7f => Error(ErrorKind::EncodedChar {
+            character: byte as char,
+            index: i + 1,
+        }),
+        byte => Error(ErrorKind::Byte { byte, index: i + 1 }),
+    }
+}
+
+/// A strict parser for the canonical string form of a [`Uuid`], with optional
+/// checks on the parsed value.
+///
+/// Every check is reported with its own [`ErrorKind`]:
+///
+/// * [`ErrorKind::NonCanonical`] if the input isn't a lowercase hyphenated
+///   UUID.
+/// * [`ErrorKind::UnknownVariant`] if unknown variants are rejected and the
+///   UUID doesn't use the RFC 9562 variant.
+/// * [`ErrorKind::RejectedVersion`] if the version of the UUID was rejected.
+///
+/// The parser can be built and used in `const` contexts.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{CanonicalParser, ErrorKind, Uuid, Version};
+/// const PARSER: CanonicalParser = CanonicalParser::new()
+///     .reject_unknown_variants()
+///     .reject_version(Version::Nil)
+///     .reject_version(Version::Md5);
+///
+/// assert!(PARSER.parse("67e55044-10b1-426f-9247-bb680e5fe0c8").is_ok());
+///
+/// assert_eq!(
+///     &ErrorKind::RejectedVersion { version: 3 },
+///     PARSER.parse("02f09a3f-1624-3b1d-8409-44eff7708208").unwrap_err().kind(),
+/// );
+/// assert_eq!(
+///     &ErrorKind::UnknownVariant { bits: 0b110 },
+///     PARSER.parse("67e55044-10b1-426f-c247-bb680e5fe0c8").unwrap_err().kind(),
+/// );
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`ErrorKind`]: enum.ErrorKind.html
+/// [`ErrorKind::NonCanonical`]: enum.ErrorKind.html#variant.NonCanonical
+/// [`ErrorKind::UnknownVariant`]: enum.ErrorKind.html#variant.UnknownVariant
+/// [`ErrorKind::RejectedVersion`]: enum.ErrorKind.html#variant.RejectedVersion
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CanonicalParser {
+    reject_unknown_variants: bool,
+    // One bit per version number, with `Version::Max` as bit 15
+    rejected_versions: u16,
+}
+
+impl CanonicalParser {
+    /// Creates a parser that accepts any UUID in canonical form.
+    pub const fn new() -> Self {
+        CanonicalParser {
+            reject_unknown_variants: false,
+            rejected_versions: 0,
+        }
+    }
+
+    /// Rejects UUIDs that don't use the variant defined by RFC 9562.
+    ///
+    /// The nil and max UUIDs are still accepted, since RFC 9562 defines them
+    /// separately from the variant. Use [`reject_version`] with
+    /// [`Version::Nil`] or [`Version::Max`] to reject them as well.
+    ///
+    /// [`reject_version`]: #method.reject_version
+    /// [`Version::Nil`]: enum.Version.html#variant.Nil
+    /// [`Version::Max`]: enum.Version.html#variant.Max
+    pub const fn reject_unknown_variants(mut self) -> Self {
+        self.reject_unknown_variants = true;
+        self
+    }
+
+    /// Rejects UUIDs of the given version.
+    ///
+    /// This can be called multiple times to reject several versions.
+    pub const fn reject_version(mut self, version: Version) -> Self {
+        self.rejected_versions |= 1 << version_bit(version);
+        self
+    }
+
+    /// Parses a `Uuid` from its canonical string form, then applies the
+    /// checks of this parser.
+    pub const fn parse(&self, input: &str) -> Result<Uuid, Error> {
+        let s = input.as_bytes();
+
+        let mut bytes = [0; 16];
+        let mut digits = 0;
+        let mut i = 0;
+
+        while i < s.len() && i < 36 {
+            let c = s[i];
+
+            if i == 8 || i == 13 || i == 18 || i == 23 {
+                if c != b'-' {
+                    return Err(Error(ErrorKind::NonCanonical {
+                        index: i + 1,
+                    }));
+                }
+            } else {
+                let digit = match c {
+                    b'0'..=b'9' => c - b'0',
+                    b'a'..=b'f' => c - b'a' + 10,
+                    _ => {
+                        return Err(Error(ErrorKind::NonCanonical {
+                            index: i + 1,
+                        }))
+                    }
+                };
+
+                bytes[digits / 2] |= digit << (4 * (1 - digits % 2));
+                digits += 1;
+            }
+
+            i += 1;
+        }
+
+        if s.len() != 36 {
+            return Err(Error(ErrorKind::NonCanonical { index: i + 1 }));
+        }
+
+        let uuid = Uuid::from_bytes(bytes);
+
+        if self.reject_unknown_variants && !uuid.is_nil() && !uuid.is_max() {
+            match uuid.get_variant() {
+                Variant::RFC4122 => (),
+                _ => {
+                    return Err(Error(ErrorKind::UnknownVariant {
+                        bits: bytes[8] >> 5,
+                    }))
+                }
+            }
+        }
+
+        if let Some(version) = uuid.get_version() {
+            if self.rejected_versions & (1 << version_bit(version)) != 0 {
+                return Err(Error(ErrorKind::RejectedVersion {
+                    version: bytes[6] >> 4,
+                }));
+            }
+        }
+
+        Ok(uuid)
+    }
+}
+
+const fn version_bit(version: Version) -> u32 {
+    match version {
+        Version::Max => 15,
+        version => version as u32,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{std::string::ToString, tests::new};
+    use crate::{
+        std::string::{String, ToString},
+        tests::new,
+    };
 
     #[test]
     fn test_parse_uuid_v4_valid() {
@@ -312,6 +964,597 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_lenient_valid() {
+        let expected =
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        for input in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            " \t67e55044-10b1-426f-9247-bb680e5fe0c8\r\n",
+            "URN:UUID:67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "Urn:Uuid:67e5504410b1426f9247bb680e5fe0c8",
+            "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+            "'67e55044-10b1-426f-9247-bb680e5fe0c8'",
+            "(67e55044-10b1-426f-9247-bb680e5fe0c8)",
+            "[67e55044-10b1-426f-9247-bb680e5fe0c8]",
+            " \"{ 67e55044-10b1-426f-9247-bb680e5fe0c8 }\" ",
+            "\"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+            "{0x67e5504410b1426f9247bb680e5fe0c8}",
+            "0X67E5504410B1426F9247BB680E5FE0C8",
+            "67e55044-10b1-426f-9247bb680e5fe0c8",
+            "67e5-5044-10b1-426f-9247-bb68-0e5f-e0c8",
+            "-67e55044--10b1426f9247bb680e5fe0c8-",
+        ]
+        .iter()
+        {
+            assert_eq!(Uuid::parse_lenient(input), Ok(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_lenient_invalid() {
+        assert_eq!(
+            Uuid::parse_lenient(""),
+            Err(Error(ErrorKind::DigitCount { count: 0 }))
+        );
+
+        assert_eq!(
+            Uuid::parse_lenient("  67e55044-10b1-426f-9247-bb680e5fe0c "),
+            Err(Error(ErrorKind::DigitCount { count: 31 }))
+        );
+
+        assert_eq!(
+            Uuid::parse_lenient("67e55044-10b1-426f-9247-bb680e5fe0c8a"),
+            Err(Error(ErrorKind::DigitCount { count: 33 }))
+        );
+
+        assert_eq!(
+            Uuid::parse_lenient("{67e5504410b1426f9247bb680e5fe0c8a}"),
+            Err(Error(ErrorKind::DigitCount { count: 33 }))
+        );
+
+        // Only one prefix is stripped
+        assert_eq!(
+            Uuid::parse_lenient("urn:uuid:0x67e5504410b1426f9247bb680e5fe0c8"),
+            Err(Error(ErrorKind::Char {
+                character: 'x',
+                index: 11,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_lenient("  (67e55044-10b1-426f-9247-bb680e5fe0cz) "),
+            Err(Error(ErrorKind::Char {
+                character: 'z',
+                index: 39,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_lenient(
+                " urn:uuid:67e55044 10b1-426f-9247-bb680e5fe0c8"
+            ),
+            Err(Error(ErrorKind::Char {
+                character: ' ',
+                index: 19,
+            }))
+        );
+
+        // Unmatched wrappers are left in place
+        assert_eq!(
+            Uuid::parse_lenient("{67e55044-10b1-426f-9247-bb680e5fe0c8)"),
+            Err(Error(ErrorKind::Char {
+                character: '{',
+                index: 1,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_lenient("67e55044-10b1-426f-9247-bb680e5fe0c\u{e9}"),
+            Err(Error(ErrorKind::Char {
+                character: '\u{e9}',
+                index: 36,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_canonical() {
+        let uuid = new();
+
+        assert_eq!(
+            Uuid::parse_canonical(&uuid.hyphenated().to_string()),
+            Ok(uuid)
+        );
+        assert_eq!(
+            Uuid::parse_canonical("00000000-0000-0000-0000-000000000000"),
+            Ok(Uuid::nil())
+        );
+
+        const UUID: Result<Uuid, Error> =
+            Uuid::parse_canonical("67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert_eq!(
+            UUID,
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+    }
+
+    #[test]
+    fn test_parse_canonical_invalid() {
+        let err = |index| Err(Error(ErrorKind::NonCanonical { index }));
+
+        assert_eq!(
+            Uuid::parse_canonical("67e55044-10b1-426f-9247-bb680e5fe0C8"),
+            err(35)
+        );
+        assert_eq!(
+            Uuid::parse_canonical("67e5504410b1426f9247bb680e5fe0c8"),
+            err(9)
+        );
+        assert_eq!(
+            Uuid::parse_canonical("{67e55044-10b1-426f-9247-bb680e5fe0c8}"),
+            err(1)
+        );
+        assert_eq!(
+            Uuid::parse_canonical(
+                "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"
+            ),
+            err(1)
+        );
+        assert_eq!(
+            Uuid::parse_canonical("67e55044-10b1-426f-9247-bb680e5fe0c"),
+            err(36)
+        );
+        assert_eq!(
+            Uuid::parse_canonical("67e55044-10b1-426f-9247-bb680e5fe0c8 "),
+            err(37)
+        );
+        assert_eq!(
+            Uuid::parse_canonical("67e55044-10b1-426f-9247-bb680e5fe0c\u{e9}"),
+            err(36)
+        );
+        assert_eq!(
+            Uuid::parse_canonical(" 7e55044-10b1-426f-9247-bb680e5fe0c8"),
+            err(1)
+        );
+        assert_eq!(
+            Uuid::parse_canonical("67e55044-10b1+426f-9247-bb680e5fe0c8"),
+            err(14)
+        );
+        assert_eq!(Uuid::parse_canonical(""), err(1));
+    }
+
+    #[test]
+    fn test_canonical_parser_checks() {
+        let parser = CanonicalParser::new().reject_unknown_variants();
+
+        assert_eq!(
+            parser.parse("67e55044-10b1-426f-c247-bb680e5fe0c8"),
+            Err(Error(ErrorKind::UnknownVariant { bits: 0b110 }))
+        );
+        assert_eq!(
+            parser.parse("67e55044-10b1-426f-1247-bb680e5fe0c8"),
+            Err(Error(ErrorKind::UnknownVariant { bits: 0b000 }))
+        );
+        assert_eq!(
+            parser.parse("67e55044-10b1-426f-e247-bb680e5fe0c8"),
+            Err(Error(ErrorKind::UnknownVariant { bits: 0b111 }))
+        );
+        assert!(parser.parse("67e55044-10b1-426f-a247-bb680e5fe0c8").is_ok());
+        assert!(parser.parse("00000000-0000-0000-0000-000000000000").is_ok());
+        assert!(parser.parse("ffffffff-ffff-ffff-ffff-ffffffffffff").is_ok());
+
+        // Without the check, any variant is accepted
+        assert!(CanonicalParser::new()
+            .parse("67e55044-10b1-426f-e247-bb680e5fe0c8")
+            .is_ok());
+
+        let parser = CanonicalParser::new()
+            .reject_version(Version::Mac)
+            .reject_version(Version::Nil)
+            .reject_version(Version::Max);
+
+        assert_eq!(
+            parser.parse("f3b4958c-52a1-11e7-802a-010203040506"),
+            Err(Error(ErrorKind::RejectedVersion { version: 1 }))
+        );
+        assert_eq!(
+            parser.parse("00000000-0000-0000-0000-000000000000"),
+            Err(Error(ErrorKind::RejectedVersion { version: 0 }))
+        );
+        assert_eq!(
+            parser.parse("ffffffff-ffff-ffff-ffff-ffffffffffff"),
+            Err(Error(ErrorKind::RejectedVersion { version: 15 }))
+        );
+        assert!(parser.parse("67e55044-10b1-426f-9247-bb680e5fe0c8").is_ok());
+
+        // Unrecognized versions other than nil and max aren't rejected
+        assert!(parser.parse("67e55044-10b1-f26f-9247-bb680e5fe0c8").is_ok());
+        assert!(parser.parse("67e55044-10b1-026f-9247-bb680e5fe0c8").is_ok());
+
+        // The format is checked before the value
+        assert_eq!(
+            parser.parse("F3B4958C-52A1-11E7-802A-010203040506"),
+            Err(Error(ErrorKind::NonCanonical { index: 1 }))
+        );
+    }
+
+    #[test]
+    fn test_parse_ascii() {
+        let uuid = new();
+
+        for input in [
+            uuid.hyphenated().to_string(),
+            uuid.simple().to_string(),
+            uuid.urn().to_string(),
+            uuid.braced().to_string(),
+        ]
+        .iter()
+        {
+            assert_eq!(Uuid::parse_ascii(input.as_bytes()), Ok(uuid));
+            assert_eq!(Uuid::try_parse_ascii(input.as_bytes()), Ok(uuid));
+        }
+
+        assert_eq!(
+            Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5fe0c"),
+            Err(Error(ErrorKind::Other))
+        );
+    }
+
+    #[test]
+    fn test_parse_ascii_invalid() {
+        // Errors for valid UTF-8 match the ones for strings
+        for input in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e55044-10b1-426f-9247-bb680e5fe0cz",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c\u{e9}}",
+            "urn:uuid:67e55044-10b1-426f-9247",
+            "67e5504410b1426f9247bb680e5fe0c",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Uuid::parse_ascii(input.as_bytes()),
+                Uuid::parse_str(input)
+            );
+        }
+
+        assert_eq!(
+            Uuid::parse_ascii(b"\xff7e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Err(Error(ErrorKind::Byte {
+                byte: 0xff,
+                index: 1,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_ascii(
+                b"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0\xc3"
+            ),
+            Err(Error(ErrorKind::Byte {
+                byte: 0xc3,
+                index: 44,
+            }))
+        );
+
+        // An invalid sequence is reported at its first byte even if it
+        // starts like a multibyte character
+        assert_eq!(
+            Uuid::parse_ascii(b"{67e55044-10b1-426f-9247-bb680e5fe0\xe2\x82}"),
+            Err(Error(ErrorKind::Byte {
+                byte: 0xe2,
+                index: 36,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_ascii(b"67e55044-10b1-426f-9247-bb680e5f\xe2\x82\xac0"),
+            Err(Error(ErrorKind::Char {
+                character: '\u{20ac}',
+                index: 33,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_base32() {
+        let uuid = new();
+
+        assert_eq!(Uuid::parse_base32(&uuid.base32().to_string()), Ok(uuid));
+        assert_eq!(
+            Uuid::parse_base32(
+                uuid.base32().encode_lower(&mut Uuid::encode_buffer())
+            ),
+            Ok(uuid)
+        );
+
+        assert_eq!(
+            Uuid::parse_base32("00000000000000000000000000"),
+            Ok(Uuid::nil())
+        );
+        assert_eq!(
+            Uuid::parse_base32("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Ok(Uuid::max())
+        );
+        assert_eq!(
+            Uuid::parse_base32("0000000000000000000000000I"),
+            Ok(Uuid::from_u128(1))
+        );
+        assert_eq!(
+            Uuid::parse_base32("olOLiI00000000000000000000"),
+            Uuid::parse_base32("01011100000000000000000000")
+        );
+
+        const UUID: Result<Uuid, Error> =
+            Uuid::parse_base32("01ARZ3NDEKTSV4RRFFQ69G5FAV");
+        assert_eq!(
+            UUID,
+            Uuid::parse_str("01563e3a-b5d3-d676-4c61-efb99302bd5b")
+        );
+    }
+
+    #[test]
+    fn test_parse_base32_invalid() {
+        assert_eq!(
+            Uuid::parse_base32(""),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 26,
+                len: 0,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base32("01ARZ3NDEKTSV4RRFFQ69G5FA"),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 26,
+                len: 25,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base32("01ARZ3NDEKTSV4RRFFQ69G5FAVV"),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 26,
+                len: 27,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base32("01ARZ3NDEKTSV4RRFFQ69G5FAU"),
+            Err(Error(ErrorKind::EncodedChar {
+                character: 'U',
+                index: 26,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base32("01ARZ3NDEK-TSV4RRFFQ69G5FAV"),
+            Err(Error(ErrorKind::EncodedChar {
+                character: '-',
+                index: 11,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base32("01ARZ3NDEKTSV4RRFFQ69G5FA\u{e9}"),
+            Err(Error(ErrorKind::Byte {
+                byte: 0xc3,
+                index: 26,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base32("01ARZ3NDEKTSV4RRFFQ69G5FA\u{1f600}"),
+            Err(Error(ErrorKind::Byte {
+                byte: 0xf0,
+                index: 26,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base32("80000000000000000000000000"),
+            Err(Error(ErrorKind::EncodedOverflow))
+        );
+    }
+
+    #[test]
+    fn test_parse_base64url() {
+        let uuid = new();
+
+        assert_eq!(
+            Uuid::parse_base64url(&uuid.base64url().to_string()),
+            Ok(uuid)
+        );
+
+        assert_eq!(
+            Uuid::parse_base64url("AAAAAAAAAAAAAAAAAAAAAA"),
+            Ok(Uuid::nil())
+        );
+        assert_eq!(
+            Uuid::parse_base64url("_____________________w"),
+            Ok(Uuid::max())
+        );
+        assert_eq!(
+            Uuid::parse_base64url("AAAAAAAAAAAAAAAAAAAAAQ"),
+            Ok(Uuid::from_u128(1))
+        );
+
+        const UUID: Result<Uuid, Error> =
+            Uuid::parse_base64url("k22gH5q9TZ2AxwKvhcgiqA");
+        assert_eq!(
+            UUID,
+            Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")
+        );
+    }
+
+    #[test]
+    fn test_parse_base64url_invalid() {
+        assert_eq!(
+            Uuid::parse_base64url("k22gH5q9TZ2AxwKvhcgiq"),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 22,
+                len: 21,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base64url("k22gH5q9TZ2AxwKvhcgiqA=="),
+            Err(Error(ErrorKind::EncodedChar {
+                character: '=',
+                index: 23,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base64url("+RaMXs6yT6q2vzKb85-h5A"),
+            Err(Error(ErrorKind::EncodedChar {
+                character: '+',
+                index: 1,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base64url("-RaMXs6yT6q2vzKb85/h5A"),
+            Err(Error(ErrorKind::EncodedChar {
+                character: '/',
+                index: 19,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base64url("k22gH5q9TZ2AxwKvhcgiqB"),
+            Err(Error(ErrorKind::EncodedOverflow))
+        );
+        assert_eq!(
+            Uuid::parse_base64url("__________________________"),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 22,
+                len: 26,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_base58() {
+        let uuid = new();
+
+        assert_eq!(Uuid::parse_base58(&uuid.base58().to_string()), Ok(uuid));
+
+        assert_eq!(
+            Uuid::parse_base58("1111111111111111111111"),
+            Ok(Uuid::nil())
+        );
+        assert_eq!(
+            Uuid::parse_base58("YcVfxkQb6JRzqk5kF2tNLv"),
+            Ok(Uuid::max())
+        );
+        assert_eq!(
+            Uuid::parse_base58("1111111111111111111112"),
+            Ok(Uuid::from_u128(1))
+        );
+
+        const UUID: Result<Uuid, Error> =
+            Uuid::parse_base58("KCtqn2CDK64hXvWfYwhRnP");
+        assert_eq!(
+            UUID,
+            Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")
+        );
+    }
+
+    #[test]
+    fn test_parse_base58_invalid() {
+        assert_eq!(
+            Uuid::parse_base58("2"),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 22,
+                len: 1,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base58("11111111111111111111112"),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 22,
+                len: 23,
+            }))
+        );
+
+        for (character, index) in
+            [('0', 5), ('O', 1), ('I', 22), ('l', 10)].iter().copied()
+        {
+            let mut input = ['1'; 22];
+            input[index - 1] = character;
+
+            assert_eq!(
+                Uuid::parse_base58(&input.iter().collect::<String>()),
+                Err(Error(ErrorKind::EncodedChar { character, index }))
+            );
+        }
+
+        assert_eq!(
+            Uuid::parse_base58("YcVfxkQb6JRzqk5kF2tNLw"),
+            Err(Error(ErrorKind::EncodedOverflow))
+        );
+        assert_eq!(
+            Uuid::parse_base58("zzzzzzzzzzzzzzzzzzzzzz"),
+            Err(Error(ErrorKind::EncodedOverflow))
+        );
+    }
+
+    #[test]
+    fn test_parse_base36() {
+        let uuid = new();
+
+        assert_eq!(Uuid::parse_base36(&uuid.base36().to_string()), Ok(uuid));
+        assert_eq!(
+            Uuid::parse_base36(
+                uuid.base36().encode_upper(&mut Uuid::encode_buffer())
+            ),
+            Ok(uuid)
+        );
+
+        assert_eq!(
+            Uuid::parse_base36("0000000000000000000000000"),
+            Ok(Uuid::nil())
+        );
+        assert_eq!(
+            Uuid::parse_base36("F5LXX1ZZ5PNORYNQGLHZMSP33"),
+            Ok(Uuid::max())
+        );
+
+        const UUID: Result<Uuid, Error> =
+            Uuid::parse_base36("8q7mlcgv6pekrk0havp5wkcyw");
+        assert_eq!(
+            UUID,
+            Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")
+        );
+    }
+
+    #[test]
+    fn test_parse_base36_invalid() {
+        assert_eq!(
+            Uuid::parse_base36("8q7mlcgv6pekrk0havp5wkcy"),
+            Err(Error(ErrorKind::EncodedLength {
+                expected: 25,
+                len: 24,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base36("8q7mlcgv6pe-rk0havp5wkcyw"),
+            Err(Error(ErrorKind::EncodedChar {
+                character: '-',
+                index: 12,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base36("8q7mlcgv6pekrk0havp5wkcy\u{df}"),
+            Err(Error(ErrorKind::Byte {
+                byte: 0xc3,
+                index: 25,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_base36("f5lxx1zz5pnorynqglhzmsp34"),
+            Err(Error(ErrorKind::EncodedOverflow))
+        );
+        assert_eq!(
+            Uuid::parse_base36("zzzzzzzzzzzzzzzzzzzzzzzzz"),
+            Err(Error(ErrorKind::EncodedOverflow))
+        );
+    }
+
     #[test]
     fn test_roundtrip_default() {
         let uuid_orig = new();
@@ -1,39 +1,304 @@
-#[cfg(feature = "v4")]
+#[cfg(any(feature = "v4", feature = "v7"))]
 pub(crate) fn bytes() -> [u8; 16] {
-    #[cfg(not(feature = "fast-rng"))]
+    let mut bytes = [0u8; 16];
+
+    fill(&mut bytes);
+
+    bytes
+}
+
+#[cfg(any(feature = "v1", feature = "v4", feature = "v7"))]
+pub(crate) fn fill(buf: &mut [u8]) {
+    #[cfg(feature = "testing")]
+    {
+        if crate::testing::fill_override(buf) {
+            return;
+        }
+    }
+
+    #[cfg(feature = "thread-local-rng")]
+    {
+        local::fill(buf);
+    }
+
+    #[cfg(all(feature = "fast-rng", not(feature = "thread-local-rng")))]
     {
-        let mut bytes = [0u8; 16];
+        rand::RngCore::fill_bytes(&mut rand::thread_rng(), buf);
+    }
 
-        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
+    #[cfg(not(any(feature = "fast-rng", feature = "thread-local-rng")))]
+    {
+        getrandom::getrandom(buf).unwrap_or_else(|err| {
             // NB: getrandom::Error has no source; this is adequate display
             panic!("could not retrieve random bytes for uuid: {}", err)
         });
-
-        bytes
     }
+}
+
+#[cfg(feature = "v4")]
+pub(crate) fn try_bytes() -> Result<[u8; 16], crate::Error> {
+    let mut bytes = [0u8; 16];
 
-    #[cfg(feature = "fast-rng")]
+    #[cfg(feature = "testing")]
     {
-        rand::random()
+        if crate::testing::fill_override(&mut bytes) {
+            return Ok(bytes);
+        }
     }
+
+    // NB: this always uses `getrandom`, even with `fast-rng` or
+    // `thread-local-rng`, because their generators panic if they can't be
+    // seeded
+    getrandom::getrandom(&mut bytes).map_err(|err| {
+        crate::Error(crate::error::ErrorKind::Rng {
+            code: err.code().get(),
+        })
+    })?;
+
+    Ok(bytes)
 }
 
 #[cfg(feature = "v1")]
 pub(crate) fn u16() -> u16 {
-    #[cfg(not(feature = "fast-rng"))]
-    {
-        let mut bytes = [0u8; 2];
+    let mut bytes = [0u8; 2];
 
-        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
-            // NB: getrandom::Error has no source; this is adequate display
-            panic!("could not retrieve random bytes for uuid: {}", err)
-        });
+    fill(&mut bytes);
+
+    ((bytes[0] as u16) << 8) | (bytes[1] as u16)
+}
+
+/// A cryptographically secure generator kept per thread and seeded from
+/// `getrandom`.
+///
+/// The generator is reseeded after producing `RESEED_THRESHOLD` bytes, and,
+/// on Linux, whenever the process ID changes so a forked child never repeats
+/// the output of its parent.
+#[cfg(feature = "thread-local-rng")]
+mod local {
+    use crate::std::cell::RefCell;
+    #[cfg(target_os = "linux")]
+    use crate::std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+    use rand_chacha::{
+        rand_core::{RngCore, SeedableRng},
+        ChaCha12Rng,
+    };
 
-        ((bytes[0] as u16) << 8) | (bytes[1] as u16)
+    const RESEED_THRESHOLD: usize = 64 * 1024;
+
+    struct LocalRng {
+        rng: ChaCha12Rng,
+        remaining: usize,
+        #[cfg(target_os = "linux")]
+        pid: libc::pid_t,
     }
 
-    #[cfg(feature = "fast-rng")]
-    {
-        rand::random()
+    impl LocalRng {
+        fn new() -> Self {
+            let mut seed = <ChaCha12Rng as SeedableRng>::Seed::default();
+
+            getrandom::getrandom(&mut seed).unwrap_or_else(|err| {
+                // NB: getrandom::Error has no source; this is adequate display
+                panic!("could not retrieve random bytes for uuid: {}", err)
+            });
+
+            LocalRng {
+                rng: ChaCha12Rng::from_seed(seed),
+                remaining: RESEED_THRESHOLD,
+                #[cfg(target_os = "linux")]
+                pid: current_pid(),
+            }
+        }
+
+        fn is_stale(&self, len: usize) -> bool {
+            #[cfg(target_os = "linux")]
+            {
+                if self.pid != current_pid() {
+                    return true;
+                }
+            }
+
+            self.remaining < len
+        }
+    }
+
+    /// The ID of the current process, or `0` if it hasn't been looked up yet.
+    ///
+    /// This is refreshed in the child after a `fork` so detecting a change
+    /// doesn't need a system call per UUID.
+    #[cfg(target_os = "linux")]
+    static PID: AtomicI32 = AtomicI32::new(0);
+
+    #[cfg(target_os = "linux")]
+    static PID_HANDLER_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+    #[cfg(target_os = "linux")]
+    extern "C" fn refresh_pid() {
+        // SAFETY: `getpid` is always successful and has no preconditions
+        PID.store(unsafe { libc::getpid() }, Ordering::Release);
+    }
+
+    #[cfg(target_os = "linux")]
+    fn current_pid() -> libc::pid_t {
+        let pid = PID.load(Ordering::Acquire);
+        if pid != 0 {
+            return pid;
+        }
+
+        if !PID_HANDLER_REGISTERED.swap(true, Ordering::AcqRel) {
+            // SAFETY: `refresh_pid` only calls `getpid` and stores an atomic,
+            // which are both async-signal-safe. If registration fails, the
+            // cached ID is never published and every call falls back to
+            // `getpid` below
+            if unsafe { libc::pthread_atfork(None, None, Some(refresh_pid)) }
+                == 0
+            {
+                // Only publish the cached ID once the handler that keeps it
+                // up to date is in place
+                refresh_pid();
+                return PID.load(Ordering::Acquire);
+            }
+        }
+
+        // SAFETY: `getpid` is always successful and has no preconditions
+        unsafe { libc::getpid() }
+    }
+
+    crate::std::thread_local! {
+        static RNG: RefCell<Option<LocalRng>> = RefCell::new(None);
+    }
+
+    pub(super) fn fill(buf: &mut [u8]) {
+        RNG.with(|rng| {
+            let mut rng = rng.borrow_mut();
+
+            if rng.as_ref().map_or(false, |rng| rng.is_stale(buf.len())) {
+                *rng = None;
+            }
+
+            let rng = rng.get_or_insert_with(LocalRng::new);
+
+            rng.rng.fill_bytes(buf);
+            rng.remaining = rng.remaining.saturating_sub(buf.len());
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[cfg(target_arch = "wasm32")]
+        use wasm_bindgen_test::*;
+
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn test_reseed_after_threshold() {
+            let mut buf = [0; 1024];
+
+            fill(&mut buf);
+            let first =
+                RNG.with(|rng| rng.borrow().as_ref().unwrap().rng.get_seed());
+
+            for _ in 0..RESEED_THRESHOLD / buf.len() {
+                fill(&mut buf);
+            }
+            let second =
+                RNG.with(|rng| rng.borrow().as_ref().unwrap().rng.get_seed());
+
+            assert_ne!(first, second);
+        }
+
+        #[test]
+        #[cfg(target_os = "linux")]
+        fn test_reseed_after_pid_change() {
+            let mut buf = [0; 16];
+
+            fill(&mut buf);
+            let first = RNG.with(|rng| {
+                let mut rng = rng.borrow_mut();
+                let rng = rng.as_mut().unwrap();
+
+                // Pretend the process has been forked since seeding
+                rng.pid = rng.pid.wrapping_add(1);
+                rng.rng.get_seed()
+            });
+
+            fill(&mut buf);
+            let second = RNG.with(|rng| {
+                let rng = rng.borrow();
+                let rng = rng.as_ref().unwrap();
+
+                assert_eq!(rng.pid, current_pid());
+                rng.rng.get_seed()
+            });
+
+            assert_ne!(first, second);
+        }
+
+        // Forking while the test harness has other threads running isn't
+        // sound, so this only runs when asked for, on a single thread:
+        //
+        // cargo test --features thread-local-rng -- --ignored --test-threads=1
+        #[test]
+        #[ignore]
+        #[cfg(target_os = "linux")]
+        fn test_reseed_after_fork() {
+            let mut parent = [0; 16];
+            fill(&mut parent);
+
+            let mut fds = [0; 2];
+
+            // SAFETY: the child only uses the generator, writes to the pipe,
+            // and exits without unwinding or running destructors
+            unsafe {
+                assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+
+                match libc::fork() {
+                    -1 => panic!("could not fork"),
+                    0 => {
+                        let mut child = [0u8; 16];
+                        fill(&mut child);
+
+                        let written =
+                            libc::write(fds[1], child.as_ptr().cast(), 16);
+                        libc::_exit(if written == 16 { 0 } else { 1 });
+                    }
+                    pid => {
+                        // Without reseeding, the child would produce exactly
+                        // the bytes the parent produces next
+                        fill(&mut parent);
+
+                        let mut child = [0u8; 16];
+                        let read =
+                            libc::read(fds[0], child.as_mut_ptr().cast(), 16);
+
+                        let mut status = 0;
+                        libc::waitpid(pid, &mut status, 0);
+                        libc::close(fds[0]);
+                        libc::close(fds[1]);
+
+                        assert_eq!(read, 16);
+                        assert_ne!(parent, child);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        #[cfg(not(target_arch = "wasm32"))]
+        fn test_threads_have_distinct_generators() {
+            let mut ours = [0; 16];
+            fill(&mut ours);
+
+            let theirs = crate::std::thread::spawn(|| {
+                let mut theirs = [0; 16];
+                fill(&mut theirs);
+                theirs
+            })
+            .join()
+            .unwrap();
+
+            assert_ne!(ours, theirs);
+        }
     }
 }
@@ -0,0 +1,408 @@
+//! Finding UUIDs embedded in arbitrary text.
+//!
+//! This module finds UUIDs inside free text like log lines, URLs, or JSON
+//! documents, without needing a regular expression. By default, it
+//! recognizes the hyphenated, braced, and URN formats. The simple format of
+//! 32 hexadecimal digits is more likely to match text that isn't a UUID,
+//! like a hash, so it needs to be opted into with
+//! [`Scanner::include_simple`].
+//!
+//! A UUID is only found if it's a whole word: it can't be directly preceded
+//! or followed by a letter, a digit, or an underscore. Braces delimit a UUID
+//! themselves, so a braced UUID is found wherever it appears. A URN prefix
+//! matches in any case.
+//!
+//! # Examples
+//!
+//! ```
+//! use uuid::{scan, Uuid};
+//!
+//! let log = "GET /users/67e55044-10b1-426f-9247-bb680e5fe0c8 200";
+//!
+//! let found: Vec<_> = scan::find_iter(log).collect();
+//!
+//! assert_eq!(
+//!     vec![(11..47, Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap())],
+//!     found,
+//! );
+//! ```
+//!
+//! [`Scanner::include_simple`]: struct.Scanner.html#method.include_simple
+
+use crate::{
+    parser::imp::{parse_hyphenated, parse_simple},
+    std::{iter::FusedIterator, ops::Range},
+    Uuid,
+};
+
+#[cfg(feature = "std")]
+use crate::std::{fmt, string::String};
+
+const URN_PREFIX: &[u8] = b"urn:uuid:";
+
+/// Returns an iterator over the UUIDs in `haystack`, along with the byte
+/// range each one was found at.
+///
+/// This finds UUIDs in the hyphenated, braced, and URN formats. Use a
+/// [`Scanner`] to also find UUIDs in the simple format.
+///
+/// [`Scanner`]: struct.Scanner.html
+pub fn find_iter(haystack: &str) -> FindIter<'_> {
+    Scanner::new().find_iter(haystack)
+}
+
+/// Replaces every UUID in `haystack` with the result of `replacer`.
+///
+/// This finds UUIDs in the same formats as [`find_iter`]. The whole match
+/// is replaced, including any braces or URN prefix.
+///
+/// This function requires the `std` feature to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::scan;
+/// let line = "user {67e55044-10b1-426f-9247-bb680e5fe0c8} logged in";
+///
+/// assert_eq!("user <redacted> logged in", scan::replace_all(line, |_| "<redacted>"));
+/// ```
+///
+/// [`find_iter`]: fn.find_iter.html
+#[cfg(feature = "std")]
+pub fn replace_all<F, R>(haystack: &str, replacer: F) -> String
+where
+    F: FnMut(Uuid) -> R,
+    R: fmt::Display,
+{
+    Scanner::new().replace_all(haystack, replacer)
+}
+
+/// A configurable search for UUIDs in arbitrary text.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::scan::Scanner;
+/// let scanner = Scanner::new().include_simple();
+///
+/// let found = scanner
+///     .find_iter("id=67e5504410b1426f9247bb680e5fe0c8&sha=1f3a")
+///     .count();
+///
+/// assert_eq!(1, found);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Scanner {
+    simple: bool,
+}
+
+impl Scanner {
+    /// Creates a scanner that finds UUIDs in the hyphenated, braced, and URN
+    /// formats.
+    pub const fn new() -> Self {
+        Scanner { simple: false }
+    }
+
+    /// Also finds UUIDs in the simple format of 32 hexadecimal digits.
+    pub const fn include_simple(mut self) -> Self {
+        self.simple = true;
+        self
+    }
+
+    /// Returns an iterator over the UUIDs in `haystack`, along with the
+    /// byte range each one was found at.
+    pub fn find_iter<'a>(&self, haystack: &'a str) -> FindIter<'a> {
+        FindIter {
+            haystack,
+            position: 0,
+            simple: self.simple,
+        }
+    }
+
+    /// Replaces every UUID in `haystack` with the result of `replacer`.
+    ///
+    /// This method requires the `std` feature to be enabled.
+    #[cfg(feature = "std")]
+    pub fn replace_all<F, R>(&self, haystack: &str, mut replacer: F) -> String
+    where
+        F: FnMut(Uuid) -> R,
+        R: fmt::Display,
+    {
+        use crate::std::fmt::Write;
+
+        let mut replaced = String::with_capacity(haystack.len());
+        let mut last = 0;
+
+        for (range, uuid) in self.find_iter(haystack) {
+            replaced.push_str(&haystack[last..range.start]);
+            write!(replaced, "{}", replacer(uuid))
+                .expect("writing to a String never fails");
+
+            last = range.end;
+        }
+
+        replaced.push_str(&haystack[last..]);
+        replaced
+    }
+}
+
+/// An iterator over the UUIDs found in a string.
+///
+/// This is created by [`find_iter`] or [`Scanner::find_iter`].
+///
+/// [`find_iter`]: fn.find_iter.html
+/// [`Scanner::find_iter`]: struct.Scanner.html#method.find_iter
+#[derive(Clone, Debug)]
+pub struct FindIter<'a> {
+    haystack: &'a str,
+    position: usize,
+    simple: bool,
+}
+
+impl<'a> FindIter<'a> {
+    /// Tries to match a UUID starting at `start`, returning the end of the
+    /// match.
+    fn match_at(&self, start: usize) -> Option<(usize, Uuid)> {
+        let s = self.haystack.as_bytes();
+
+        match s[start] {
+            b'{' => {
+                let end = start + 38;
+                if s.get(end - 1) != Some(&b'}') {
+                    return None;
+                }
+
+                let bytes = parse_hyphenated(&s[start + 1..end - 1]).ok()?;
+                Some((end, Uuid::from_bytes(bytes)))
+            }
+            b'u' | b'U' if self.is_boundary_before(start) => {
+                let prefix = s.get(start..start + URN_PREFIX.len())?;
+                if !prefix.eq_ignore_ascii_case(URN_PREFIX) {
+                    return None;
+                }
+
+                self.match_hyphenated(start + URN_PREFIX.len())
+            }
+            b if b.is_ascii_hexdigit() && self.is_boundary_before(start) => {
+                self.match_hyphenated(start)
+                    .or_else(|| self.match_simple(start))
+            }
+            _ => None,
+        }
+    }
+
+    fn match_hyphenated(&self, start: usize) -> Option<(usize, Uuid)> {
+        let end = start + 36;
+        let bytes =
+            parse_hyphenated(self.haystack.as_bytes().get(start..end)?).ok()?;
+
+        if self.is_boundary_after(end) {
+            Some((end, Uuid::from_bytes(bytes)))
+        } else {
+            None
+        }
+    }
+
+    fn match_simple(&self, start: usize) -> Option<(usize, Uuid)> {
+        if !self.simple {
+            return None;
+        }
+
+        let end = start + 32;
+        let bytes =
+            parse_simple(self.haystack.as_bytes().get(start..end)?).ok()?;
+
+        if self.is_boundary_after(end) {
+            Some((end, Uuid::from_bytes(bytes)))
+        } else {
+            None
+        }
+    }
+
+    fn is_boundary_before(&self, index: usize) -> bool {
+        !self.haystack[..index]
+            .chars()
+            .next_back()
+            .map_or(false, is_word_char)
+    }
+
+    fn is_boundary_after(&self, index: usize) -> bool {
+        !self.haystack[index..]
+            .chars()
+            .next()
+            .map_or(false, is_word_char)
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl<'a> Iterator for FindIter<'a> {
+    type Item = (Range<usize>, Uuid);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Matches only start at ASCII bytes, which are always on a char
+        // boundary, so the haystack is only ever sliced at char boundaries
+        while self.position < self.haystack.len() {
+            let start = self.position;
+
+            if let Some((end, uuid)) = self.match_at(start) {
+                self.position = end;
+                return Some((start..end, uuid));
+            }
+
+            self.position += 1;
+        }
+
+        None
+    }
+}
+
+impl<'a> FusedIterator for FindIter<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use crate::std::{format, vec::Vec};
+
+    const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    fn uuid() -> Uuid {
+        Uuid::parse_str(UUID).unwrap()
+    }
+
+    fn find(haystack: &str) -> Vec<(usize, usize)> {
+        find_iter(haystack)
+            .map(|(range, _)| (range.start, range.end))
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_find_formats() {
+        let haystack = format!(
+            "a {} b {{{}}} c urn:uuid:{} d URN:UUID:{}",
+            UUID,
+            UUID,
+            UUID,
+            UUID.to_uppercase()
+        );
+
+        let found = find_iter(&haystack).collect::<Vec<_>>();
+
+        assert_eq!(
+            found,
+            [
+                (2..38, uuid()),
+                (41..79, uuid()),
+                (82..127, uuid()),
+                (130..175, uuid()),
+            ]
+        );
+
+        for (range, uuid) in found {
+            assert_eq!(Uuid::parse_lenient(&haystack[range]), Ok(uuid));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_find_word_boundaries() {
+        assert_eq!(find(UUID), [(0, 36)]);
+        assert_eq!(find(&format!("/{}/", UUID)), [(1, 37)]);
+        assert_eq!(find(&format!("\"{}\",", UUID)), [(1, 37)]);
+        assert_eq!(find(&format!("req-{}", UUID)), [(4, 40)]);
+
+        assert_eq!(find(&format!("a{}", UUID)), []);
+        assert_eq!(find(&format!("{}0", UUID)), []);
+        assert_eq!(find(&format!("_{}", UUID)), []);
+        assert_eq!(find(&format!("é{}", UUID)), []);
+        assert_eq!(find(&format!("{}é", UUID)), []);
+
+        // A URN prefix that isn't a whole word is left out of the match
+        assert_eq!(find(&format!("xurn:uuid:{}", UUID)), [(10, 46)]);
+
+        // Braces delimit the UUID themselves
+        assert_eq!(find(&format!("a{{{}}}b", UUID)), [(1, 39)]);
+        assert_eq!(find(&format!("é{{{}}}", UUID)), [(2, 40)]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_find_near_misses() {
+        assert_eq!(find(""), []);
+        assert_eq!(find("67e55044-10b1-426f-9247-bb680e5fe0c"), []);
+        assert_eq!(find("67e55044-10b1-426f-9247-bb680e5fe0cz"), []);
+        assert_eq!(find("67e55044-10b1-426f-9247bb680e5fe0c8"), []);
+        assert_eq!(find("urn:uuid:67e55044-10b1-426f-9247"), []);
+        assert_eq!(find("{67e55044-10b1-426f-9247-bb680e5fe0c8"), [(1, 37)]);
+        assert_eq!(find("67e55044-10b1-426f-9247-bb680e5fe0c8}"), [(0, 36)]);
+        assert_eq!(find("67e5504410b1426f9247bb680e5fe0c8"), []);
+        assert_eq!(
+            find("urn:uuid:{67e55044-10b1-426f-9247-bb680e5fe0c8}"),
+            [(9, 47)]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_find_simple() {
+        let scanner = Scanner::new().include_simple();
+        let find = |haystack: &str| {
+            scanner
+                .find_iter(haystack)
+                .map(|(range, _)| (range.start, range.end))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            scanner
+                .find_iter("67e5504410b1426f9247bb680e5fe0c8")
+                .collect::<Vec<_>>(),
+            [(0..32, uuid())]
+        );
+
+        assert_eq!(find("x=67e5504410b1426f9247bb680e5fe0c8&"), [(2, 34)]);
+        assert_eq!(find(&format!("{} {}", UUID, UUID)), [(0, 36), (37, 73)]);
+
+        // Longer runs of hex digits, like hashes, aren't UUIDs
+        assert_eq!(find("67e5504410b1426f9247bb680e5fe0c80"), []);
+        assert_eq!(find("da39a3ee5e6b4b0d3255bfef95601890afd80709"), []);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_find_adjacent() {
+        let haystack = format!("{},{};{{{}}}", UUID, UUID, UUID);
+
+        assert_eq!(find(&haystack), [(0, 36), (37, 73), (74, 112)]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_replace_all() {
+        let line = format!(
+            "{{\"user\":\"{}\",\"urn\":\"urn:uuid:{}\",\"n\":1}}",
+            UUID, UUID
+        );
+
+        assert_eq!(
+            replace_all(&line, |_| "***"),
+            "{\"user\":\"***\",\"urn\":\"***\",\"n\":1}"
+        );
+
+        assert_eq!(
+            replace_all(&line, |uuid| uuid.simple()),
+            "{\"user\":\"67e5504410b1426f9247bb680e5fe0c8\",\"urn\":\"67e5504410b1426f9247bb680e5fe0c8\",\"n\":1}"
+        );
+
+        assert_eq!(replace_all("no uuids here", |_| "***"), "no uuids here");
+        assert_eq!(replace_all("", |_| "***"), "");
+    }
+}
@@ -0,0 +1,358 @@
+//! Reproducible UUIDs for tests.
+//!
+//! UUIDs created from the operating system's RNG are different on every run,
+//! which makes them awkward to compare against expected output. This module
+//! provides a [`SeededGenerator`] that always creates the same sequence of
+//! valid UUIDs for the same seed, and [`with_seed`] to make [`Uuid::new_v4`]
+//! use one on the current thread for the duration of a closure.
+//!
+//! The generator is **not** cryptographically secure, and UUIDs it creates
+//! are trivially predictable. It should only be used in tests.
+//!
+//! Note that you need to enable the `testing` Cargo feature
+//! in order to use this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use uuid::testing::SeededGenerator;
+//!
+//! let mut a = SeededGenerator::new(42);
+//! let mut b = SeededGenerator::new(42);
+//!
+//! assert_eq!(a.new_v4(), b.new_v4());
+//! assert_eq!(a.new_v7(), b.new_v7());
+//! ```
+//!
+//! [`SeededGenerator`]: struct.SeededGenerator.html
+//! [`with_seed`]: fn.with_seed.html
+//! [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+
+use crate::{Builder, Uuid};
+
+#[cfg(feature = "rng")]
+use crate::std::cell::RefCell;
+
+/// A deterministic generator of UUIDs.
+///
+/// The same seed always produces the same sequence of UUIDs, across runs,
+/// platforms, and releases of this crate. Random bits are produced by
+/// [SplitMix64], with the version and variant set the same way
+/// [`Builder::from_random_bytes`] and
+/// [`Builder::from_unix_timestamp_millis`] set them.
+///
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+/// [`Builder::from_random_bytes`]: ../struct.Builder.html#method.from_random_bytes
+/// [`Builder::from_unix_timestamp_millis`]: ../struct.Builder.html#method.from_unix_timestamp_millis
+#[derive(Clone, Debug)]
+pub struct SeededGenerator {
+    state: u64,
+    unix_millis: u64,
+}
+
+impl SeededGenerator {
+    /// Creates a generator from a seed.
+    ///
+    /// V7 UUIDs created by the generator start at the Unix epoch. Use
+    /// [`SeededGenerator::with_unix_millis`] to start them at a different
+    /// time.
+    ///
+    /// [`SeededGenerator::with_unix_millis`]: #method.with_unix_millis
+    pub const fn new(seed: u64) -> Self {
+        SeededGenerator {
+            state: seed,
+            unix_millis: 0,
+        }
+    }
+
+    /// Sets the Unix timestamp in milliseconds of the next V7 UUID created
+    /// by the generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::testing::SeededGenerator;
+    /// let mut generator =
+    ///     SeededGenerator::new(42).with_unix_millis(1_645_557_742_000);
+    ///
+    /// let uuid = generator.new_v7();
+    ///
+    /// assert_eq!(1_645_557_742_000, uuid.as_u128() >> 80);
+    /// ```
+    pub const fn with_unix_millis(mut self, unix_millis: u64) -> Self {
+        self.unix_millis = unix_millis;
+        self
+    }
+
+    /// Returns the next 16 bytes produced by the generator.
+    pub fn next_bytes(&mut self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        self.fill(&mut bytes);
+
+        bytes
+    }
+
+    /// Creates the next random UUID (version 4).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{testing::SeededGenerator, Version};
+    /// let mut generator = SeededGenerator::new(42);
+    ///
+    /// let uuid = generator.new_v4();
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert_eq!(
+    ///     "bdd73226-2feb-4e95-a8ef-e333b266f103",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub fn new_v4(&mut self) -> Uuid {
+        Builder::from_random_bytes(self.next_bytes()).into_uuid()
+    }
+
+    /// Creates the next Unix timestamp-based UUID (version 7).
+    ///
+    /// Each UUID is stamped one millisecond after the one before it, so
+    /// UUIDs created by the same generator always sort in the order they
+    /// were created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{testing::SeededGenerator, Version};
+    /// let mut generator = SeededGenerator::new(42);
+    ///
+    /// let first = generator.new_v7();
+    /// let second = generator.new_v7();
+    ///
+    /// assert_eq!(Some(Version::SortRand), first.get_version());
+    /// assert!(first < second);
+    /// ```
+    pub fn new_v7(&mut self) -> Uuid {
+        let bytes = self.next_bytes();
+
+        let mut random_bytes = [0; 10];
+        random_bytes.copy_from_slice(&bytes[..10]);
+
+        let unix_millis = self.unix_millis;
+        self.unix_millis = self.unix_millis.wrapping_add(1);
+
+        Builder::from_unix_timestamp_millis(unix_millis, &random_bytes)
+            .into_uuid()
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let bytes = self.next_u64().to_be_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(feature = "rng")]
+crate::std::thread_local! {
+    static OVERRIDE: RefCell<Option<SeededGenerator>> = RefCell::new(None);
+}
+
+/// Runs a closure with random UUIDs on the current thread created by a
+/// [`SeededGenerator`] instead of the operating system's RNG.
+///
+/// Within `f`, [`Uuid::new_v4`] returns the same UUIDs as
+/// [`SeededGenerator::new_v4`] would for `seed`. Every other source of
+/// random bytes in this crate, like [`Uuid::try_new_v4`] and the random bits
+/// of [`Uuid::new_v7`], draws from the same generator. Timestamps still come
+/// from the system clock. Other threads are unaffected.
+///
+/// Calls can be nested, and the previous generator is restored when `f`
+/// returns or panics.
+///
+/// Note that usage of this function requires a feature of this crate that
+/// generates random bytes, such as `v4`, `v7`, or `fast-rng`, to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// // Note that this requires the `v4` feature enabled in the uuid crate.
+/// # use uuid::{testing::{with_seed, SeededGenerator}, Uuid};
+/// # fn main() {
+/// # #[cfg(feature = "v4")] {
+/// let uuid = with_seed(42, Uuid::new_v4);
+///
+/// assert_eq!(SeededGenerator::new(42).new_v4(), uuid);
+/// assert_eq!(uuid, with_seed(42, Uuid::new_v4));
+/// # }
+/// # }
+/// ```
+///
+/// [`SeededGenerator`]: struct.SeededGenerator.html
+/// [`SeededGenerator::new_v4`]: struct.SeededGenerator.html#method.new_v4
+/// [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+/// [`Uuid::try_new_v4`]: ../struct.Uuid.html#method.try_new_v4
+/// [`Uuid::new_v7`]: ../struct.Uuid.html#method.new_v7
+#[cfg(feature = "rng")]
+pub fn with_seed<R>(seed: u64, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<SeededGenerator>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            let _ =
+                OVERRIDE.try_with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let previous = OVERRIDE.with(|current| {
+        current.borrow_mut().replace(SeededGenerator::new(seed))
+    });
+    let _restore = Restore(previous);
+
+    f()
+}
+
+/// Fills `buf` from the generator installed by [`with_seed`], returning
+/// whether there was one.
+#[cfg(feature = "rng")]
+pub(crate) fn fill_override(buf: &mut [u8]) -> bool {
+    OVERRIDE
+        .try_with(|current| match &mut *current.borrow_mut() {
+            Some(generator) => {
+                generator.fill(buf);
+                true
+            }
+            None => false,
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use crate::{std::string::ToString, Variant, Version};
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_sequence_is_stable() {
+        // These must never change, since users compare them against files
+        let mut generator = SeededGenerator::new(42);
+
+        assert_eq!(
+            generator.new_v4().hyphenated().to_string(),
+            "bdd73226-2feb-4e95-a8ef-e333b266f103"
+        );
+        assert_eq!(
+            generator.new_v4().hyphenated().to_string(),
+            "47526757-130f-4f52-981c-e1ff0e4ae394"
+        );
+        assert_eq!(
+            generator.new_v7().hyphenated().to_string(),
+            "00000000-0000-79bc-985a-244823f2de44"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_valid_uuids() {
+        let mut generator =
+            SeededGenerator::new(7).with_unix_millis(1_645_557_742_000);
+
+        for _ in 0..100 {
+            let uuid = generator.new_v4();
+
+            assert_eq!(uuid.get_version(), Some(Version::Random));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        }
+
+        let mut last = Uuid::nil();
+        for i in 0..100u64 {
+            let uuid = generator.new_v7();
+
+            assert_eq!(uuid.get_version(), Some(Version::SortRand));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert_eq!(uuid.as_u128() >> 80, u128::from(1_645_557_742_000 + i));
+            assert!(uuid > last);
+
+            last = uuid;
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_different_seeds() {
+        assert_ne!(
+            SeededGenerator::new(1).new_v4(),
+            SeededGenerator::new(2).new_v4()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "v4")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_with_seed() {
+        let mut generator = SeededGenerator::new(42);
+
+        let (first, second, fallible) = with_seed(42, || {
+            (Uuid::new_v4(), Uuid::new_v4(), Uuid::try_new_v4().unwrap())
+        });
+
+        assert_eq!(first, generator.new_v4());
+        assert_eq!(second, generator.new_v4());
+        assert_eq!(fallible, generator.new_v4());
+
+        assert_ne!(Uuid::new_v4(), SeededGenerator::new(42).new_v4());
+    }
+
+    #[test]
+    #[cfg(feature = "v4")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_with_seed_nested() {
+        let (outer, inner, restored) = with_seed(1, || {
+            let outer = Uuid::new_v4();
+            let inner = with_seed(2, Uuid::new_v4);
+
+            (outer, inner, Uuid::new_v4())
+        });
+
+        let mut generator = SeededGenerator::new(1);
+
+        assert_eq!(outer, generator.new_v4());
+        assert_eq!(inner, SeededGenerator::new(2).new_v4());
+        assert_eq!(restored, generator.new_v4());
+    }
+
+    #[test]
+    #[cfg(all(feature = "v4", not(target_arch = "wasm32")))]
+    fn test_with_seed_restored_after_panic() {
+        let result = crate::std::panic::catch_unwind(|| {
+            with_seed(42, || panic!("expected"));
+        });
+
+        assert!(result.is_err());
+        assert_ne!(Uuid::new_v4(), SeededGenerator::new(42).new_v4());
+    }
+
+    #[test]
+    #[cfg(all(feature = "v4", not(target_arch = "wasm32")))]
+    fn test_with_seed_other_threads() {
+        with_seed(42, || {
+            let theirs =
+                crate::std::thread::spawn(Uuid::new_v4).join().unwrap();
+
+            assert_ne!(theirs, SeededGenerator::new(42).new_v4());
+        });
+    }
+}
@@ -0,0 +1,808 @@
+//! Generating UUIDs from timestamps.
+//!
+//! Timestamps are used in a few UUID versions as a source of decentralized
+//! uniqueness (as in V1 and V6), and to enable natural database sorting (as
+//! in V6 and V7). [`Uuid::get_timestamp`] reads the timestamp back out of any
+//! of these versions as a [`Timestamp`].
+//!
+//! [`Uuid::get_timestamp`]: ../struct.Uuid.html#method.get_timestamp
+//! [`Timestamp`]: struct.Timestamp.html
+
+use crate::{Uuid, Version};
+
+#[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+use crate::error::{Error, ErrorKind};
+
+#[cfg(feature = "std")]
+use crate::std::{
+    convert::TryFrom,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The number of 100 ns ticks between the UUID epoch
+/// `1582-10-15 00:00:00` and the Unix epoch `1970-01-01 00:00:00`.
+pub(crate) const UUID_TICKS_BETWEEN_EPOCHS: u64 = 0x01B2_1DD2_1381_4000;
+
+/// The largest number of 100 ns ticks that fits in the 60 bits a UUID has
+/// available for its timestamp.
+#[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+const UUID_TICKS_MAX: u64 = (1 << 60) - 1;
+
+/// Stores the number of nanoseconds from an epoch and a counter for ensuring
+/// V1 ids generated on the same host are unique.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timestamp {
+    ticks: u64,
+    counter: u16,
+}
+
+impl Timestamp {
+    /// Construct a `Timestamp` from its raw component values: an RFC4122
+    /// timestamp and counter.
+    ///
+    /// RFC4122, which defines the V1 UUID, specifies a 60-byte timestamp format
+    /// as the number of 100-nanosecond intervals elapsed since 00:00:00.00,
+    /// 15 Oct 1582, "the date of the Gregorian reform of the Christian
+    /// calendar."
+    ///
+    /// The counter value is used to differentiate between ids generated by
+    /// the same host computer in rapid succession (i.e. with the same observed
+    /// time). See the [`ClockSequence`] trait for a generic interface to any
+    /// counter generators that might be used.
+    ///
+    /// Internally, the timestamp is stored as a `u64`. For this reason, dates
+    /// prior to October 1582 are not supported.
+    ///
+    /// [`ClockSequence`]: trait.ClockSequence.html
+    pub const fn from_rfc4122(ticks: u64, counter: u16) -> Self {
+        Timestamp { ticks, counter }
+    }
+
+    /// Construct a `Timestamp` from a unix timestamp and sequence-generating
+    /// `context`.
+    ///
+    /// A unix timestamp represents the elapsed time since Jan 1 1970. Libc's
+    /// `clock_gettime` and other popular implementations traditionally
+    /// represent this duration as a `timespec`: a struct with `u64` and
+    /// `u32` fields representing the seconds, and "subsecond" or fractional
+    /// nanoseconds elapsed since the timestamp's second began,
+    /// respectively.
+    ///
+    /// This constructs a `Timestamp` from the seconds and fractional
+    /// nanoseconds of a unix timestamp, converting the duration since 1970
+    /// into the number of 100-nanosecond intervals since 00:00:00.00, 15
+    /// Oct 1582 specified by RFC4122 and used internally by `Timestamp`.
+    ///
+    /// The function is not guaranteed to produce monotonically increasing
+    /// values however. There is a slight possibility that two successive
+    /// equal time values could be supplied and the sequence counter wraps back
+    /// over to 0.
+    ///
+    /// If uniqueness and monotonicity is required, the user is responsible for
+    /// ensuring that the time value always increases between calls (including
+    /// between restarts of the process and device).
+    pub fn from_unix(
+        context: impl ClockSequence,
+        seconds: u64,
+        subsec_nanos: u32,
+    ) -> Self {
+        let counter = context.generate_sequence(seconds, subsec_nanos);
+        let ticks = UUID_TICKS_BETWEEN_EPOCHS
+            + seconds * 10_000_000
+            + u64::from(subsec_nanos) / 100;
+
+        Timestamp { ticks, counter }
+    }
+
+    /// Construct a `Timestamp` from the current system time and a
+    /// sequence-generating `context`.
+    ///
+    /// This is the same as calling [`Timestamp::from_unix`] with the time
+    /// elapsed since the Unix epoch according to [`SystemTime::now`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the system time is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::timestamp::{ClockSequence, Timestamp};
+    /// # struct Context;
+    /// # impl ClockSequence for Context {
+    /// #     fn generate_sequence(&self, _: u64, _: u32) -> u16 { 42 }
+    /// # }
+    /// let ts = Timestamp::now(Context);
+    ///
+    /// assert_eq!(42, ts.to_rfc4122().1);
+    /// ```
+    ///
+    /// [`Timestamp::from_unix`]: #method.from_unix
+    /// [`SystemTime::now`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html#method.now
+    #[cfg(feature = "std")]
+    pub fn now(context: impl ClockSequence) -> Self {
+        Timestamp::from_clock(context, SystemClock)
+    }
+
+    /// Construct a `Timestamp` from the current time of a [`Clock`] and a
+    /// sequence-generating `context`.
+    ///
+    /// This is the same as calling [`Timestamp::from_unix`] with the time
+    /// returned by `clock`. Passing a [`ManualClock`] makes it possible to
+    /// control the time in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::timestamp::{ClockSequence, ManualClock, Timestamp};
+    /// # struct Context;
+    /// # impl ClockSequence for Context {
+    /// #     fn generate_sequence(&self, _: u64, _: u32) -> u16 { 42 }
+    /// # }
+    /// let clock = ManualClock::new(1_645_557_742, 0);
+    /// let ts = Timestamp::from_clock(Context, &clock);
+    ///
+    /// assert_eq!((1_645_557_742, 0), ts.to_unix());
+    /// ```
+    ///
+    /// [`Clock`]: trait.Clock.html
+    /// [`ManualClock`]: struct.ManualClock.html
+    /// [`Timestamp::from_unix`]: #method.from_unix
+    pub fn from_clock(context: impl ClockSequence, clock: impl Clock) -> Self {
+        let (seconds, subsec_nanos) = clock.now();
+
+        Timestamp::from_unix(context, seconds, subsec_nanos)
+    }
+
+    /// Returns the raw RFC4122 timestamp and counter values stored by the
+    /// `Timestamp`.
+    ///
+    /// The timestamp (the first, `u64` element in the tuple) represents the
+    /// number of 100-nanosecond intervals since 00:00:00.00, 15 Oct 1582.
+    /// The counter is used to differentiate between ids generated on the
+    /// same host computer with the same observed time.
+    pub const fn to_rfc4122(&self) -> (u64, u16) {
+        (self.ticks, self.counter)
+    }
+
+    /// Returns the timestamp converted to the seconds and fractional
+    /// nanoseconds since Jan 1 1970.
+    ///
+    /// Internally, the time is stored in 100-nanosecond intervals,
+    /// thus the maximum precision represented by the fractional nanoseconds
+    /// value is less than its unit size (100 ns vs. 1 ns).
+    ///
+    /// Times before Jan 1 1970 saturate to `(0, 0)`. Timestamps can hold
+    /// times back to 15 Oct 1582; convert them into a `SystemTime`, or a
+    /// `chrono` or `time` date with the matching features, to handle those.
+    pub const fn to_unix(&self) -> (u64, u32) {
+        if self.ticks < UUID_TICKS_BETWEEN_EPOCHS {
+            return (0, 0);
+        }
+
+        (
+            (self.ticks - UUID_TICKS_BETWEEN_EPOCHS) / 10_000_000,
+            ((self.ticks - UUID_TICKS_BETWEEN_EPOCHS) % 10_000_000) as u32
+                * 100,
+        )
+    }
+
+    /// Returns the timestamp converted into nanoseconds elapsed since Jan 1
+    /// 1970. Internally, the time is stored in 100-nanosecond intervals,
+    /// thus the maximum precision represented is less than the units it is
+    /// measured in (100 ns vs. 1 ns). The value returned represents the
+    /// same duration as [`Timestamp::to_unix`]; this provides it in nanosecond
+    /// units for convenience, and also saturates to `0` for times before
+    /// Jan 1 1970.
+    ///
+    /// [`Timestamp::to_unix`]: #method.to_unix
+    pub const fn to_unix_nanos(&self) -> u64 {
+        if self.ticks < UUID_TICKS_BETWEEN_EPOCHS {
+            return 0;
+        }
+
+        (self.ticks - UUID_TICKS_BETWEEN_EPOCHS) * 100
+    }
+
+    /// Returns the timestamp converted to the seconds and fractional
+    /// nanoseconds since Jan 1 1970, with negative seconds for times before
+    /// then.
+    #[cfg(any(feature = "v1", feature = "chrono", feature = "time"))]
+    pub(crate) fn to_unix_signed(self) -> (i64, u32) {
+        let ticks = self.ticks as i128 - UUID_TICKS_BETWEEN_EPOCHS as i128;
+
+        (
+            ticks.div_euclid(10_000_000) as i64,
+            ticks.rem_euclid(10_000_000) as u32 * 100,
+        )
+    }
+
+    /// Construct a `Timestamp` with a counter of zero from the seconds and
+    /// fractional nanoseconds since Jan 1 1970, with negative seconds for
+    /// times before then.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(crate) fn try_from_unix_signed(
+        seconds: i64,
+        subsec_nanos: u32,
+    ) -> Result<Self, Error> {
+        let ticks = seconds as i128 * 10_000_000
+            + (subsec_nanos / 100) as i128
+            + UUID_TICKS_BETWEEN_EPOCHS as i128;
+
+        if ticks < 0 || ticks > UUID_TICKS_MAX as i128 {
+            return Err(Error(ErrorKind::TimestampRange));
+        }
+
+        Ok(Timestamp::from_rfc4122(ticks as u64, 0))
+    }
+
+    /// Replaces the counter with one generated by `context`.
+    ///
+    /// The context is given the time since Jan 1 1970. The negative seconds
+    /// of times before then are passed as their two's complement, so every
+    /// tick is still given a distinct time.
+    #[cfg(feature = "v1")]
+    pub(crate) fn with_sequence(self, context: impl ClockSequence) -> Self {
+        let (seconds, subsec_nanos) = self.to_unix_signed();
+
+        Timestamp {
+            ticks: self.ticks,
+            counter: context.generate_sequence(seconds as u64, subsec_nanos),
+        }
+    }
+
+    /// Returns the number of milliseconds since Jan 1 1970, or `None` for
+    /// times before then.
+    #[cfg(feature = "v7")]
+    pub(crate) const fn to_unix_millis(self) -> Option<u64> {
+        if self.ticks < UUID_TICKS_BETWEEN_EPOCHS {
+            return None;
+        }
+
+        Some((self.ticks - UUID_TICKS_BETWEEN_EPOCHS) / 10_000)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Timestamp> for SystemTime {
+    /// Converts a `Timestamp` into the `SystemTime` it represents.
+    ///
+    /// Unlike [`Timestamp::to_unix`], this also supports times before the
+    /// Unix epoch.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the platform's `SystemTime` can't represent the
+    /// timestamp. On Windows, for instance, `SystemTime` can't represent
+    /// times before 1601.
+    ///
+    /// [`Timestamp::to_unix`]: #method.to_unix
+    fn from(ts: Timestamp) -> Self {
+        if ts.ticks >= UUID_TICKS_BETWEEN_EPOCHS {
+            UNIX_EPOCH + ticks_to_duration(ts.ticks - UUID_TICKS_BETWEEN_EPOCHS)
+        } else {
+            UNIX_EPOCH - ticks_to_duration(UUID_TICKS_BETWEEN_EPOCHS - ts.ticks)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = Error;
+
+    /// Converts a `SystemTime` into a `Timestamp` with a counter of zero.
+    ///
+    /// The time is truncated to the 100-nanosecond precision of a
+    /// `Timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the time is before 15 Oct 1582, or too far
+    /// in the future to fit in the 60 bits available to a UUID timestamp.
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let ticks = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => {
+                let after = after.as_nanos() / 100;
+
+                if after
+                    > u128::from(UUID_TICKS_MAX - UUID_TICKS_BETWEEN_EPOCHS)
+                {
+                    return Err(Error(ErrorKind::TimestampRange));
+                }
+
+                UUID_TICKS_BETWEEN_EPOCHS + after as u64
+            }
+            Err(before) => {
+                // Round up so that times between two ticks are truncated
+                // towards the earlier one, the same as after the epoch
+                let nanos = before.duration().as_nanos();
+                let before = nanos / 100 + u128::from(nanos % 100 != 0);
+
+                if before > u128::from(UUID_TICKS_BETWEEN_EPOCHS) {
+                    return Err(Error(ErrorKind::TimestampRange));
+                }
+
+                UUID_TICKS_BETWEEN_EPOCHS - before as u64
+            }
+        };
+
+        Ok(Timestamp::from_rfc4122(ticks, 0))
+    }
+}
+
+#[cfg(feature = "std")]
+fn ticks_to_duration(ticks: u64) -> Duration {
+    Duration::new(ticks / 10_000_000, (ticks % 10_000_000) as u32 * 100)
+}
+
+/// A trait that abstracts over generation of UUID v1 "Clock Sequence" values.
+///
+/// # References
+///
+/// * [Clock Sequence in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.1.5)
+pub trait ClockSequence {
+    /// Return a 16-bit number that will be used as the "clock sequence" in
+    /// the UUID. The number must be different if the time has changed since
+    /// the last time a clock sequence was requested.
+    fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> u16;
+}
+
+impl<'a, T: ClockSequence + ?Sized> ClockSequence for &'a T {
+    fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> u16 {
+        (**self).generate_sequence(seconds, subsec_nanos)
+    }
+}
+
+/// A source of the current time for time-based UUIDs.
+///
+/// Time-based generators read the time from a `Clock` rather than the system
+/// clock directly, so it can be replaced. [`SystemClock`] reads the system
+/// clock, and [`ManualClock`] returns a time set by the caller, which is
+/// useful for making tests deterministic.
+///
+/// [`SystemClock`]: struct.SystemClock.html
+/// [`ManualClock`]: struct.ManualClock.html
+pub trait Clock {
+    /// Returns the current time as the seconds and fractional nanoseconds
+    /// elapsed since Jan 1 1970.
+    fn now(&self) -> (u64, u32);
+}
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now(&self) -> (u64, u32) {
+        (**self).now()
+    }
+}
+
+/// A [`Clock`] that reads the system time.
+///
+/// Note that usage of this type requires the `std` feature of this crate to
+/// be enabled.
+///
+/// # Panics
+///
+/// [`Clock::now`] panics if the system time is before the Unix epoch.
+///
+/// [`Clock`]: trait.Clock.html
+/// [`Clock::now`]: trait.Clock.html#tymethod.now
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> (u64, u32) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("the system time is before the Unix epoch");
+
+        (now.as_secs(), now.subsec_nanos())
+    }
+}
+
+/// A [`Clock`] that only changes when it's told to.
+///
+/// This is meant for tests, where stepping the time explicitly makes the
+/// ordering and uniqueness of time-based UUIDs deterministic without having
+/// to sleep. The clock is thread-safe, so it can be shared with generators
+/// running on other threads.
+///
+/// Note that usage of this type requires the `std` feature of this crate to
+/// be enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// use uuid::timestamp::{Clock, ManualClock};
+///
+/// let clock = ManualClock::new(1_645_557_742, 0);
+/// assert_eq!((1_645_557_742, 0), clock.now());
+///
+/// clock.advance(Duration::from_millis(1));
+/// assert_eq!((1_645_557_742, 1_000_000), clock.now());
+/// ```
+///
+/// [`Clock`]: trait.Clock.html
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<Duration>,
+}
+
+#[cfg(feature = "std")]
+impl ManualClock {
+    /// Creates a clock that's stopped at the given seconds and fractional
+    /// nanoseconds since Jan 1 1970.
+    ///
+    /// Any whole seconds in `subsec_nanos` are carried over into `seconds`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if carrying over the seconds in `subsec_nanos`
+    /// overflows `seconds`.
+    pub fn new(seconds: u64, subsec_nanos: u32) -> Self {
+        ManualClock {
+            now: Mutex::new(Duration::new(seconds, subsec_nanos)),
+        }
+    }
+
+    /// Sets the clock to the given seconds and fractional nanoseconds since
+    /// Jan 1 1970.
+    ///
+    /// The time can be set backwards, to test how generators behave when the
+    /// system clock is adjusted.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if carrying over the seconds in `subsec_nanos`
+    /// overflows `seconds`.
+    pub fn set(&self, seconds: u64, subsec_nanos: u32) {
+        *self.lock() = Duration::new(seconds, subsec_nanos);
+    }
+
+    /// Moves the clock forward by `duration`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the time overflows a `u64` number of seconds.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.lock();
+
+        *now = now
+            .checked_add(duration)
+            .expect("overflow when advancing the clock");
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Duration> {
+        // The time is never left half-updated, so it's still usable after a
+        // panic on another thread
+        self.now.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for ManualClock {
+    fn now(&self) -> (u64, u32) {
+        let now = *self.lock();
+
+        (now.as_secs(), now.subsec_nanos())
+    }
+}
+
+impl Uuid {
+    /// Returns an optional [`Timestamp`] storing the timestamp and
+    /// counter portion parsed from a time-based UUID.
+    ///
+    /// Returns `None` if the supplied UUID is not V1, V2, V6, or V7.
+    ///
+    /// The V1 timestamp format defined in RFC4122 specifies a 60-bit
+    /// integer representing the number of 100-nanosecond intervals
+    /// since 00:00:00.00, 15 Oct 1582. The other versions are decoded into
+    /// the same format:
+    ///
+    /// * V6 UUIDs store the same timestamp with its fields in reverse order.
+    /// * V2 UUIDs replace the low 32 bits of the timestamp and the low 8 bits
+    ///   of the counter with other data, so those bits are zero. The
+    ///   timestamp is only precise to about 7 minutes.
+    /// * V7 UUIDs store a Unix timestamp in milliseconds and have no
+    ///   counter, so the counter is zero.
+    ///
+    /// [`Timestamp`] offers several options for converting the raw RFC4122
+    /// value into more commonly-used formats, such as a unix timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let v1 = Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846")?;
+    /// let v7 = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert_eq!(
+    ///     (1_645_557_742, 0),
+    ///     v1.get_timestamp().unwrap().to_unix(),
+    /// );
+    /// assert_eq!(
+    ///     (1_645_557_742, 0),
+    ///     v7.get_timestamp().unwrap().to_unix(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Timestamp`]: timestamp/struct.Timestamp.html
+    pub const fn get_timestamp(&self) -> Option<Timestamp> {
+        let bytes = self.as_bytes();
+
+        let ticks: u64 = match self.get_version() {
+            Some(Version::Mac) => {
+                ((bytes[6] & 0x0F) as u64) << 56
+                    | (bytes[7] as u64) << 48
+                    | (bytes[4] as u64) << 40
+                    | (bytes[5] as u64) << 32
+                    | (bytes[0] as u64) << 24
+                    | (bytes[1] as u64) << 16
+                    | (bytes[2] as u64) << 8
+                    | (bytes[3] as u64)
+            }
+            Some(Version::Dce) => {
+                ((bytes[6] & 0x0F) as u64) << 56
+                    | (bytes[7] as u64) << 48
+                    | (bytes[4] as u64) << 40
+                    | (bytes[5] as u64) << 32
+            }
+            Some(Version::SortMac) => {
+                (bytes[0] as u64) << 52
+                    | (bytes[1] as u64) << 44
+                    | (bytes[2] as u64) << 36
+                    | (bytes[3] as u64) << 28
+                    | (bytes[4] as u64) << 20
+                    | (bytes[5] as u64) << 12
+                    | ((bytes[6] & 0x0F) as u64) << 8
+                    | (bytes[7] as u64)
+            }
+            Some(Version::SortRand) => {
+                let millis = (bytes[0] as u64) << 40
+                    | (bytes[1] as u64) << 32
+                    | (bytes[2] as u64) << 24
+                    | (bytes[3] as u64) << 16
+                    | (bytes[4] as u64) << 8
+                    | (bytes[5] as u64);
+
+                return Some(Timestamp::from_rfc4122(
+                    UUID_TICKS_BETWEEN_EPOCHS + millis * 10_000,
+                    0,
+                ));
+            }
+            _ => return None,
+        };
+
+        let counter: u16 = match self.get_version() {
+            Some(Version::Dce) => ((bytes[8] & 0x3F) as u16) << 8,
+            _ => ((bytes[8] & 0x3F) as u16) << 8 | (bytes[9] as u16),
+        };
+
+        Some(Timestamp::from_rfc4122(ticks, counter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_timestamp_v1_v6() {
+        // From RFC 9562, Appendix A.1 and A.5
+        let v1 =
+            Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap();
+        let v6 =
+            Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9f6bdeced846").unwrap();
+
+        let expected = Timestamp::from_rfc4122(0x1EC_9414_C232_AB00, 0x33C8);
+
+        assert_eq!(v1.get_timestamp(), Some(expected));
+        assert_eq!(v6.get_timestamp(), Some(expected));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_timestamp_v2() {
+        let v2 =
+            Uuid::parse_str("000003e8-9414-21ec-b300-9f6bdeced846").unwrap();
+
+        assert_eq!(
+            v2.get_timestamp(),
+            Some(Timestamp::from_rfc4122(0x1EC_9414_0000_0000, 0x3300))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_timestamp_v7() {
+        // From RFC 9562, Appendix A.6
+        let v7 =
+            Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+
+        let ts = v7.get_timestamp().unwrap();
+
+        assert_eq!(ts.to_unix(), (1_645_557_742, 0));
+        assert_eq!(ts.to_rfc4122().1, 0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_timestamp_not_time_based() {
+        for s in &[
+            "00000000-0000-0000-0000-000000000000",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            "2489e9ad-2ee2-8e00-8ec9-32d5f69181c0",
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+        ] {
+            let uuid = Uuid::parse_str(s).unwrap();
+
+            assert_eq!(uuid.get_timestamp(), None);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_time_roundtrip() {
+        for &ticks in &[
+            0,
+            1,
+            UUID_TICKS_BETWEEN_EPOCHS - 1,
+            UUID_TICKS_BETWEEN_EPOCHS,
+            UUID_TICKS_BETWEEN_EPOCHS + 16_455_577_420_000_000,
+            UUID_TICKS_MAX,
+        ] {
+            let ts = Timestamp::from_rfc4122(ticks, 0);
+            let time = SystemTime::from(ts);
+
+            assert_eq!(Timestamp::try_from(time).unwrap(), ts);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_time_truncates() {
+        let after = UNIX_EPOCH + Duration::from_nanos(150);
+        let before = UNIX_EPOCH - Duration::from_nanos(50);
+
+        assert_eq!(
+            Timestamp::try_from(after).unwrap().to_rfc4122().0,
+            UUID_TICKS_BETWEEN_EPOCHS + 1
+        );
+        assert_eq!(
+            Timestamp::try_from(before).unwrap().to_rfc4122().0,
+            UUID_TICKS_BETWEEN_EPOCHS - 1
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_time_out_of_range() {
+        let too_early = UNIX_EPOCH
+            - ticks_to_duration(UUID_TICKS_BETWEEN_EPOCHS)
+            - Duration::from_nanos(100);
+        let too_late = UNIX_EPOCH
+            + ticks_to_duration(UUID_TICKS_MAX - UUID_TICKS_BETWEEN_EPOCHS)
+            + Duration::from_nanos(100);
+
+        assert!(Timestamp::try_from(too_early).is_err());
+        assert!(Timestamp::try_from(too_late).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_unix_before_epoch() {
+        let ts = Timestamp::from_rfc4122(UUID_TICKS_BETWEEN_EPOCHS - 1, 0);
+
+        assert_eq!(ts.to_unix(), (0, 0));
+        assert_eq!(ts.to_unix_nanos(), 0);
+
+        let ts = Timestamp::from_rfc4122(UUID_TICKS_BETWEEN_EPOCHS + 1, 0);
+
+        assert_eq!(ts.to_unix(), (0, 100));
+        assert_eq!(ts.to_unix_nanos(), 100);
+    }
+
+    #[test]
+    #[cfg(feature = "v1")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_with_sequence_before_epoch() {
+        use crate::std::cell::Cell;
+
+        struct Seen(Cell<(u64, u32)>);
+
+        impl ClockSequence for Seen {
+            fn generate_sequence(
+                &self,
+                seconds: u64,
+                subsec_nanos: u32,
+            ) -> u16 {
+                self.0.set((seconds, subsec_nanos));
+                0
+            }
+        }
+
+        let seen = Seen(Cell::new((0, 0)));
+
+        Timestamp::from_rfc4122(UUID_TICKS_BETWEEN_EPOCHS - 1, 0)
+            .with_sequence(&seen);
+        assert_eq!(seen.0.get(), (-1i64 as u64, 999_999_900));
+
+        Timestamp::from_rfc4122(0, 0).with_sequence(&seen);
+        assert_eq!(seen.0.get(), (-12_219_292_800i64 as u64, 0));
+
+        Timestamp::from_rfc4122(UUID_TICKS_BETWEEN_EPOCHS, 0)
+            .with_sequence(&seen);
+        assert_eq!(seen.0.get(), (0, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_now() {
+        struct Fixed;
+
+        impl ClockSequence for Fixed {
+            fn generate_sequence(&self, _: u64, _: u32) -> u16 {
+                7
+            }
+        }
+
+        let before = SystemTime::now();
+        let ts = Timestamp::now(Fixed);
+        let after = SystemTime::now();
+
+        assert_eq!(ts.to_rfc4122().1, 7);
+        assert!(
+            Timestamp::try_from(before).unwrap().to_rfc4122().0 <= ts.ticks
+        );
+        assert!(ts.ticks <= Timestamp::try_from(after).unwrap().to_rfc4122().0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_manual_clock() {
+        let clock = ManualClock::new(1_645_557_742, 999_999_999);
+
+        assert_eq!(clock.now(), (1_645_557_742, 999_999_999));
+
+        clock.advance(Duration::from_nanos(1));
+        assert_eq!(clock.now(), (1_645_557_743, 0));
+
+        clock.set(1_000, 500);
+        assert_eq!(clock.now(), (1_000, 500));
+
+        let clock = ManualClock::new(1, 1_500_000_000);
+        assert_eq!(clock.now(), (2, 500_000_000));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_clock() {
+        struct Fixed;
+
+        impl ClockSequence for Fixed {
+            fn generate_sequence(&self, _: u64, _: u32) -> u16 {
+                7
+            }
+        }
+
+        let clock = ManualClock::new(1_645_557_742, 123_456_789);
+        let ts = Timestamp::from_clock(Fixed, &clock);
+
+        assert_eq!(ts, Timestamp::from_unix(Fixed, 1_645_557_742, 123_456_789));
+    }
+}
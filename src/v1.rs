@@ -3,141 +3,43 @@
 //! Note that you need to enable the `v1` Cargo feature
 //! in order to use this module.
 
-use crate::{Uuid, Version};
+use crate::{error::Error, std::convert::TryInto, Uuid};
+
+#[cfg(feature = "std")]
+use crate::{
+    error::ErrorKind,
+    std::{
+        ffi::OsString,
+        fs::{self, File},
+        io::{self, Write},
+        path::{Path, PathBuf},
+        sync::{Mutex, MutexGuard, PoisonError},
+    },
+    timestamp::{Clock, UUID_TICKS_BETWEEN_EPOCHS},
+};
 
 use atomic::Atomic;
 
-/// The number of 100 ns ticks between the UUID epoch
-/// `1582-10-15 00:00:00` and the Unix epoch `1970-01-01 00:00:00`.
-const UUID_TICKS_BETWEEN_EPOCHS: u64 = 0x01B2_1DD2_1381_4000;
+pub use crate::timestamp::{ClockSequence, Timestamp};
+
+/// The largest value a clock sequence can hold, since RFC4122 reserves 2 of
+/// its 16 bits for the variant.
+#[cfg(feature = "std")]
+const CLOCK_SEQ_MAX: u16 = u16::MAX >> 2;
 
 /// A thread-safe, stateful context for the v1 generator to help ensure
 /// process-wide uniqueness.
+///
+/// The clock sequence is incremented for every UUID, regardless of the time,
+/// so it wraps after 16383 UUIDs. If more UUIDs than that can be created
+/// within a single tick, use a [`TickContext`] instead.
+///
+/// [`TickContext`]: struct.TickContext.html
 #[derive(Debug)]
 pub struct Context {
     count: Atomic<u16>,
 }
 
-/// Stores the number of nanoseconds from an epoch and a counter for ensuring
-/// V1 ids generated on the same host are unique.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Timestamp {
-    ticks: u64,
-    counter: u16,
-}
-
-impl Timestamp {
-    /// Construct a `Timestamp` from its raw component values: an RFC4122
-    /// timestamp and counter.
-    ///
-    /// RFC4122, which defines the V1 UUID, specifies a 60-byte timestamp format
-    /// as the number of 100-nanosecond intervals elapsed since 00:00:00.00,
-    /// 15 Oct 1582, "the date of the Gregorian reform of the Christian
-    /// calendar."
-    ///
-    /// The counter value is used to differentiate between ids generated by
-    /// the same host computer in rapid succession (i.e. with the same observed
-    /// time). See the [`ClockSequence`] trait for a generic interface to any
-    /// counter generators that might be used.
-    ///
-    /// Internally, the timestamp is stored as a `u64`. For this reason, dates
-    /// prior to October 1582 are not supported.
-    ///
-    /// [`ClockSequence`]: trait.ClockSequence.html
-    pub const fn from_rfc4122(ticks: u64, counter: u16) -> Self {
-        Timestamp { ticks, counter }
-    }
-
-    /// Construct a `Timestamp` from a unix timestamp and sequence-generating
-    /// `context`.
-    ///
-    /// A unix timestamp represents the elapsed time since Jan 1 1970. Libc's
-    /// `clock_gettime` and other popular implementations traditionally
-    /// represent this duration as a `timespec`: a struct with `u64` and
-    /// `u32` fields representing the seconds, and "subsecond" or fractional
-    /// nanoseconds elapsed since the timestamp's second began,
-    /// respectively.
-    ///
-    /// This constructs a `Timestamp` from the seconds and fractional
-    /// nanoseconds of a unix timestamp, converting the duration since 1970
-    /// into the number of 100-nanosecond intervals since 00:00:00.00, 15
-    /// Oct 1582 specified by RFC4122 and used internally by `Timestamp`.
-    ///
-    /// The function is not guaranteed to produce monotonically increasing
-    /// values however. There is a slight possibility that two successive
-    /// equal time values could be supplied and the sequence counter wraps back
-    /// over to 0.
-    ///
-    /// If uniqueness and monotonicity is required, the user is responsible for
-    /// ensuring that the time value always increases between calls (including
-    /// between restarts of the process and device).
-    pub fn from_unix(
-        context: impl ClockSequence,
-        seconds: u64,
-        subsec_nanos: u32,
-    ) -> Self {
-        let counter = context.generate_sequence(seconds, subsec_nanos);
-        let ticks = UUID_TICKS_BETWEEN_EPOCHS
-            + seconds * 10_000_000
-            + u64::from(subsec_nanos) / 100;
-
-        Timestamp { ticks, counter }
-    }
-
-    /// Returns the raw RFC4122 timestamp and counter values stored by the
-    /// `Timestamp`.
-    ///
-    /// The timestamp (the first, `u64` element in the tuple) represents the
-    /// number of 100-nanosecond intervals since 00:00:00.00, 15 Oct 1582.
-    /// The counter is used to differentiate between ids generated on the
-    /// same host computer with the same observed time.
-    pub const fn to_rfc4122(&self) -> (u64, u16) {
-        (self.ticks, self.counter)
-    }
-
-    /// Returns the timestamp converted to the seconds and fractional
-    /// nanoseconds since Jan 1 1970.
-    ///
-    /// Internally, the time is stored in 100-nanosecond intervals,
-    /// thus the maximum precision represented by the fractional nanoseconds
-    /// value is less than its unit size (100 ns vs. 1 ns).
-    pub const fn to_unix(&self) -> (u64, u32) {
-        (
-            (self.ticks - UUID_TICKS_BETWEEN_EPOCHS) / 10_000_000,
-            ((self.ticks - UUID_TICKS_BETWEEN_EPOCHS) % 10_000_000) as u32
-                * 100,
-        )
-    }
-
-    /// Returns the timestamp converted into nanoseconds elapsed since Jan 1
-    /// 1970. Internally, the time is stored in 100-nanosecond intervals,
-    /// thus the maximum precision represented is less than the units it is
-    /// measured in (100 ns vs. 1 ns). The value returned represents the
-    /// same duration as [`Timestamp::to_unix`]; this provides it in nanosecond
-    /// units for convenience.
-    pub const fn to_unix_nanos(&self) -> u64 {
-        (self.ticks - UUID_TICKS_BETWEEN_EPOCHS) * 100
-    }
-}
-
-/// A trait that abstracts over generation of UUID v1 "Clock Sequence" values.
-///
-/// # References
-///
-/// * [Clock Sequence in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.1.5)
-pub trait ClockSequence {
-    /// Return a 16-bit number that will be used as the "clock sequence" in
-    /// the UUID. The number must be different if the time has changed since
-    /// the last time a clock sequence was requested.
-    fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> u16;
-}
-
-impl<'a, T: ClockSequence + ?Sized> ClockSequence for &'a T {
-    fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> u16 {
-        (**self).generate_sequence(seconds, subsec_nanos)
-    }
-}
-
 impl Uuid {
     /// Create a new UUID (version 1) using a time value + sequence +
     /// *NodeId*.
@@ -154,7 +56,8 @@ impl Uuid {
     ///    purpose. However you can create your own [`ClockSequence`]
     ///    implementation, if [`Context`] does not meet your needs).
     ///
-    /// The NodeID must be exactly 6 bytes long.
+    /// The NodeID must be exactly 6 bytes long. A [`NodeId`] can be used to
+    /// look one up from the system's network interfaces.
     ///
     /// Note that usage of this method requires the `v1` feature of this crate
     /// to be enabled.
@@ -197,19 +100,22 @@ impl Uuid {
     /// );
     /// ```
     ///
-    /// [`Timestamp`]: v1/struct.Timestamp.html
-    /// [`ClockSequence`]: v1/trait.ClockSequence.html
+    /// [`Timestamp`]: timestamp/struct.Timestamp.html
+    /// [`ClockSequence`]: timestamp/trait.ClockSequence.html
     /// [`Context`]: v1/struct.Context.html
+    /// [`NodeId`]: node/struct.NodeId.html
     pub const fn new_v1(ts: Timestamp, node_id: &[u8; 6]) -> Self {
-        let time_low = (ts.ticks & 0xFFFF_FFFF) as u32;
-        let time_mid = ((ts.ticks >> 32) & 0xFFFF) as u16;
+        let (ticks, counter) = ts.to_rfc4122();
+
+        let time_low = (ticks & 0xFFFF_FFFF) as u32;
+        let time_mid = ((ticks >> 32) & 0xFFFF) as u16;
         let time_high_and_version =
-            (((ts.ticks >> 48) & 0x0FFF) as u16) | (1 << 12);
+            (((ticks >> 48) & 0x0FFF) as u16) | (1 << 12);
 
         let mut d4 = [0; 8];
 
-        d4[0] = (((ts.counter & 0x3F00) >> 8) as u8) | 0x80;
-        d4[1] = (ts.counter & 0xFF) as u8;
+        d4[0] = (((counter & 0x3F00) >> 8) as u8) | 0x80;
+        d4[1] = (counter & 0xFF) as u8;
         d4[2] = node_id[0];
         d4[3] = node_id[1];
         d4[4] = node_id[2];
@@ -220,41 +126,127 @@ impl Uuid {
         Uuid::from_fields(time_low, time_mid, time_high_and_version, &d4)
     }
 
-    /// Returns an optional [`Timestamp`] storing the timestamp and
-    /// counter portion parsed from a V1 UUID.
+    /// Create a new UUID (version 1) using the current system time, a
+    /// process-wide clock sequence, and *NodeId*.
+    ///
+    /// The clock sequence comes from a [`Context`] shared by every call to
+    /// this method in the process. It's seeded with a random value when the
+    /// `rng` feature is enabled, and from the system time otherwise. To use
+    /// your own [`ClockSequence`] or [`Clock`], create a [`Timestamp`] with
+    /// [`Timestamp::from_clock`] and pass it to [`Uuid::new_v1`] instead.
     ///
-    /// Returns `None` if the supplied UUID is not V1.
+    /// The NodeID must be exactly 6 bytes long.
+    ///
+    /// Note that usage of this method requires the `v1` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
     ///
-    /// The V1 timestamp format defined in RFC4122 specifies a 60-bit
-    /// integer representing the number of 100-nanosecond intervals
-    /// since 00:00:00.00, 15 Oct 1582.
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::now_v1(&[1, 2, 3, 4, 5, 6]);
     ///
-    /// [`Timestamp`] offers several options for converting the raw RFC4122
-    /// value into more commonly-used formats, such as a unix timestamp.
+    /// assert_eq!(Some(Version::Mac), uuid.get_version());
+    /// ```
     ///
-    /// [`Timestamp`]: v1/struct.Timestamp.html
-    pub const fn get_timestamp(&self) -> Option<Timestamp> {
-        match self.get_version() {
-            Some(Version::Mac) => {
-                let ticks: u64 = ((self.as_bytes()[6] & 0x0F) as u64) << 56
-                    | ((self.as_bytes()[7]) as u64) << 48
-                    | ((self.as_bytes()[4]) as u64) << 40
-                    | ((self.as_bytes()[5]) as u64) << 32
-                    | ((self.as_bytes()[0]) as u64) << 24
-                    | ((self.as_bytes()[1]) as u64) << 16
-                    | ((self.as_bytes()[2]) as u64) << 8
-                    | (self.as_bytes()[3] as u64);
+    /// [`Context`]: v1/struct.Context.html
+    /// [`ClockSequence`]: timestamp/trait.ClockSequence.html
+    /// [`Clock`]: timestamp/trait.Clock.html
+    /// [`Timestamp`]: timestamp/struct.Timestamp.html
+    /// [`Timestamp::from_clock`]: timestamp/struct.Timestamp.html#method.from_clock
+    #[cfg(feature = "std")]
+    pub fn now_v1(node_id: &[u8; 6]) -> Self {
+        Uuid::new_v1(Timestamp::now(shared_context()), node_id)
+    }
 
-                let counter: u16 = ((self.as_bytes()[8] & 0x3F) as u16) << 8
-                    | (self.as_bytes()[9] as u16);
+    /// Create a new UUID (version 1) using a date and time, a
+    /// [`ClockSequence`], and *NodeId*.
+    ///
+    /// `datetime` can be anything that converts into a [`Timestamp`], such as
+    /// a `SystemTime`, or a `chrono::DateTime` or `time::OffsetDateTime` with
+    /// the `chrono` or `time` features enabled. Unlike
+    /// [`Timestamp::from_unix`], this supports dates before 1970. For those
+    /// dates, the `context` is passed the negative Unix seconds as their
+    /// two's complement, so each tick is still given a distinct time.
+    ///
+    /// The same conditions for uniqueness as [`Uuid::new_v1`] apply to this
+    /// function. The NodeID must be exactly 6 bytes long.
+    ///
+    /// Note that usage of this method requires the `v1` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if `datetime` can't be converted into a
+    /// [`Timestamp`], because it's before 15 Oct 1582 or too far in the
+    /// future.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::v1::Context;
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let context = Context::new(42);
+    /// let time = UNIX_EPOCH + Duration::new(1_497_624_119, 1234);
+    ///
+    /// let uuid = Uuid::new_v1_at(time, &context, &[1, 2, 3, 4, 5, 6])?;
+    ///
+    /// assert_eq!(
+    ///     uuid.hyphenated().to_string(),
+    ///     "f3b4958c-52a1-11e7-802a-010203040506"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Timestamp`]: timestamp/struct.Timestamp.html
+    /// [`Timestamp::from_unix`]: timestamp/struct.Timestamp.html#method.from_unix
+    /// [`ClockSequence`]: timestamp/trait.ClockSequence.html
+    pub fn new_v1_at<T>(
+        datetime: T,
+        context: impl ClockSequence,
+        node_id: &[u8; 6],
+    ) -> Result<Self, Error>
+    where
+        T: TryInto<Timestamp, Error = Error>,
+    {
+        let ts = datetime.try_into()?.with_sequence(context);
 
-                Some(Timestamp::from_rfc4122(ticks, counter))
-            }
-            _ => None,
-        }
+        Ok(Uuid::new_v1(ts, node_id))
     }
 }
 
+/// Returns the context shared by calls to `Uuid::now_v1`.
+#[cfg(feature = "std")]
+fn shared_context() -> &'static Context {
+    use crate::std::sync::Once;
+
+    static CONTEXT: Context = Context::new(0);
+    static SEED: Once = Once::new();
+
+    SEED.call_once(|| {
+        #[cfg(feature = "rng")]
+        let seed = crate::rng::u16();
+
+        // Without a source of random numbers, the sub-second part of the
+        // current time is the best guess at a value that differs between
+        // processes
+        #[cfg(not(feature = "rng"))]
+        let seed = {
+            use crate::timestamp::{Clock, SystemClock};
+
+            (SystemClock.now().1 / 100) as u16
+        };
+
+        CONTEXT.count.store(seed, atomic::Ordering::Release);
+    });
+
+    &CONTEXT
+}
+
 impl Context {
     /// Creates a thread-safe, internally mutable context to help ensure
     /// uniqueness.
@@ -287,6 +279,36 @@ impl Context {
             count: Atomic::new(crate::rng::u16()),
         }
     }
+
+    /// Creates a thread-safe, internally mutable context that's seeded with a
+    /// value from the supplied random number generator.
+    ///
+    /// This is the same as [`Context::new_random`], but uses `rng` instead of
+    /// the operating system's RNG. Any generator implementing
+    /// [`rand_core::RngCore`] can be used.
+    ///
+    /// This method requires the `rand_core` feature to also be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    /// use uuid::v1::{Context, Timestamp};
+    ///
+    /// let mut rng = ChaCha20Rng::seed_from_u64(42);
+    /// let context = Context::new_random_with(&mut rng);
+    ///
+    /// let ts = Timestamp::from_unix(&context, 1497624119, 1234);
+    /// ```
+    ///
+    /// [`Context::new_random`]: #method.new_random
+    /// [`rand_core::RngCore`]: https://docs.rs/rand_core/0.6/rand_core/trait.RngCore.html
+    #[cfg(feature = "rand_core")]
+    pub fn new_random_with(rng: &mut impl rand_core::RngCore) -> Self {
+        Self {
+            count: Atomic::new(rng.next_u32() as u16),
+        }
+    }
 }
 
 impl ClockSequence for Context {
@@ -300,6 +322,490 @@ impl ClockSequence for Context {
     }
 }
 
+/// A thread-safe context for the v1 generator that guarantees every
+/// timestamp it creates is unique.
+///
+/// Unlike [`Context`], which increments its clock sequence for every UUID, a
+/// `TickContext` keeps the same clock sequence while time advances, and only
+/// increments it for UUIDs created within the same 100 ns tick. That gives
+/// each tick the full 14-bit space of 16384 clock sequences. If the clock goes
+/// backwards, the context keeps using the last tick it saw, so timestamps
+/// from a single context never repeat.
+///
+/// Once every clock sequence for a tick has been used, the context either
+/// returns an error from [`TickContext::try_timestamp`], or waits for the
+/// clock to reach the next tick in [`TickContext::timestamp_from_clock`] and
+/// [`TickContext::now`].
+///
+/// Uniqueness is only guaranteed between UUIDs created by the same context,
+/// so it should be shared across all threads generating v1 UUIDs.
+///
+/// This type requires the `std` feature to also be enabled.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::v1::TickContext;
+/// # use uuid::Uuid;
+/// let context = TickContext::new(42);
+///
+/// let ts1 = context.try_timestamp(1_497_624_119, 1234).unwrap();
+/// let ts2 = context.try_timestamp(1_497_624_119, 1234).unwrap();
+/// let ts3 = context.try_timestamp(1_497_624_120, 1234).unwrap();
+///
+/// assert_eq!(42, ts1.to_rfc4122().1);
+/// assert_eq!(43, ts2.to_rfc4122().1);
+/// assert_eq!(42, ts3.to_rfc4122().1);
+///
+/// let uuid = Uuid::new_v1(ts3, &[1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// [`Context`]: struct.Context.html
+/// [`TickContext::try_timestamp`]: #method.try_timestamp
+/// [`TickContext::timestamp_from_clock`]: #method.timestamp_from_clock
+/// [`TickContext::now`]: #method.now
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct TickContext {
+    // The last tick, and the number of clock sequences used within it minus
+    // one
+    state: Mutex<(u64, u16)>,
+    seed: u16,
+}
+
+#[cfg(feature = "std")]
+impl TickContext {
+    /// Creates a thread-safe, internally mutable context whose clock
+    /// sequence starts at `seed`, which should be a random value.
+    pub fn new(seed: u16) -> Self {
+        TickContext {
+            state: Mutex::new((0, 0)),
+            seed: seed & CLOCK_SEQ_MAX,
+        }
+    }
+
+    /// Creates a thread-safe, internally mutable context that's seeded with a
+    /// random value.
+    ///
+    /// This method requires either the `rng` or `fast-rng` feature to also be
+    /// enabled.
+    #[cfg(feature = "rng")]
+    pub fn new_random() -> Self {
+        TickContext::new(crate::rng::u16())
+    }
+
+    /// Creates a unique timestamp for a time given as the seconds and
+    /// fractional nanoseconds elapsed since Jan 1 1970.
+    ///
+    /// If the time is earlier than the last one the context saw, the last
+    /// time is used instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if 16384 timestamps have already been created for
+    /// the same tick. Creating a timestamp for a later time will succeed.
+    pub fn try_timestamp(
+        &self,
+        seconds: u64,
+        subsec_nanos: u32,
+    ) -> Result<Timestamp, Error> {
+        let ticks = UUID_TICKS_BETWEEN_EPOCHS
+            .wrapping_add(seconds.wrapping_mul(10_000_000))
+            .wrapping_add(u64::from(subsec_nanos) / 100);
+
+        // The state is always valid, even if another thread panicked
+        let mut state =
+            self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let (last_ticks, last_used) = *state;
+
+        let (ticks, used) = if ticks > last_ticks {
+            (ticks, 0)
+        } else if last_used < CLOCK_SEQ_MAX {
+            (last_ticks, last_used + 1)
+        } else {
+            return Err(Error(ErrorKind::ClockSequenceExhausted));
+        };
+
+        *state = (ticks, used);
+
+        let clock_seq = self.seed.wrapping_add(used) & CLOCK_SEQ_MAX;
+
+        Ok(Timestamp::from_rfc4122(ticks, clock_seq))
+    }
+
+    /// Creates a unique timestamp for the current time according to
+    /// `clock`.
+    ///
+    /// If every clock sequence for the current tick has been used, this
+    /// waits for `clock` to reach the next tick.
+    pub fn timestamp_from_clock(&self, clock: impl Clock) -> Timestamp {
+        loop {
+            let (seconds, subsec_nanos) = clock.now();
+
+            if let Ok(ts) = self.try_timestamp(seconds, subsec_nanos) {
+                return ts;
+            }
+
+            crate::std::thread::yield_now();
+        }
+    }
+
+    /// Creates a unique timestamp for the current system time.
+    ///
+    /// If every clock sequence for the current tick has been used, this
+    /// waits for the system clock to reach the next tick.
+    pub fn now(&self) -> Timestamp {
+        self.timestamp_from_clock(crate::timestamp::SystemClock)
+    }
+}
+
+/// The state of a clock sequence that's kept in stable storage.
+///
+/// # References
+///
+/// * [Basic Algorithm in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.2.1)
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct State {
+    /// The last timestamp a clock sequence was generated for, as the number
+    /// of 100 ns ticks since `1582-10-15 00:00:00`.
+    pub ticks: u64,
+    /// The last clock sequence generated. Only the lower 14 bits are used.
+    pub clock_seq: u16,
+}
+
+/// Stable storage for the [`State`] of a [`StatefulContext`].
+///
+/// [`FileStore`] keeps the state in a file, but any other kind of storage
+/// can be used by implementing this trait.
+///
+/// [`State`]: struct.State.html
+/// [`StatefulContext`]: struct.StatefulContext.html
+/// [`FileStore`]: struct.FileStore.html
+#[cfg(feature = "std")]
+pub trait StateStore {
+    /// Load the last saved state, or `None` if nothing has been saved yet.
+    fn load(&self) -> io::Result<Option<State>>;
+
+    /// Save the state, replacing whatever was saved before.
+    fn save(&self, state: &State) -> io::Result<()>;
+
+    /// Replace the saved state with the one `f` computes from it, and
+    /// return the new state.
+    ///
+    /// Stores that can be shared between processes should make sure nothing
+    /// else updates the state between loading and saving it. By default,
+    /// this calls [`load`] and then [`save`] without any locking.
+    ///
+    /// [`load`]: #tymethod.load
+    /// [`save`]: #tymethod.save
+    fn update(
+        &self,
+        f: &mut dyn FnMut(Option<State>) -> State,
+    ) -> io::Result<State> {
+        let state = f(self.load()?);
+        self.save(&state)?;
+
+        Ok(state)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: StateStore + ?Sized> StateStore for &T {
+    fn load(&self) -> io::Result<Option<State>> {
+        (**self).load()
+    }
+
+    fn save(&self, state: &State) -> io::Result<()> {
+        (**self).save(state)
+    }
+
+    fn update(
+        &self,
+        f: &mut dyn FnMut(Option<State>) -> State,
+    ) -> io::Result<State> {
+        (**self).update(f)
+    }
+}
+
+/// A thread-safe context for the v1 generator that keeps its clock sequence
+/// in stable storage, so UUIDs stay unique across restarts and when the
+/// system clock goes backwards.
+///
+/// This follows the basic algorithm from RFC4122: the context remembers the
+/// last timestamp it generated a clock sequence for, and only changes the
+/// clock sequence when a timestamp isn't later than that. Timestamps equal
+/// to the last one count as not being later, since reusing the clock sequence
+/// for them would create the same UUID twice. Every time a clock sequence is
+/// generated, it's computed from the state saved in a [`StateStore`] and
+/// saved back in a single [`StateStore::update`], so contexts in several
+/// processes can share a store that supports it.
+///
+/// [`ClockSequence::generate_sequence`] can't return an error, so if updating
+/// the state fails the context keeps working from the state it holds in
+/// memory, and the error can be retrieved with
+/// [`StatefulContext::take_error`].
+///
+/// # Examples
+///
+/// ```
+/// use uuid::v1::{FileStore, StatefulContext, Timestamp};
+/// # use uuid::Uuid;
+/// # fn main() -> std::io::Result<()> {
+/// # let path = std::env::temp_dir().join(format!("uuid-doc-{}", std::process::id()));
+/// let context = StatefulContext::new(FileStore::new(&path), 42)?;
+///
+/// let uuid = Uuid::new_v1(Timestamp::now(&context), &[1, 2, 3, 4, 5, 6]);
+///
+/// assert!(context.take_error().is_none());
+/// # std::fs::remove_file(&path)?;
+/// # let _ = std::fs::remove_file(path.with_extension("lock"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [Basic Algorithm in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.2.1)
+///
+/// [`StateStore`]: trait.StateStore.html
+/// [`StateStore::update`]: trait.StateStore.html#method.update
+/// [`ClockSequence::generate_sequence`]: trait.ClockSequence.html#tymethod.generate_sequence
+/// [`StatefulContext::take_error`]: #method.take_error
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct StatefulContext<S> {
+    state: Mutex<(State, Option<io::Error>)>,
+    store: S,
+}
+
+#[cfg(feature = "std")]
+impl<S: StateStore> StatefulContext<S> {
+    /// Creates a context from the state saved in `store`.
+    ///
+    /// If nothing has been saved yet, the clock sequence starts at `seed`,
+    /// which should be a random value.
+    pub fn new(store: S, seed: u16) -> io::Result<Self> {
+        let state = store.load()?.unwrap_or(State {
+            ticks: 0,
+            clock_seq: seed & CLOCK_SEQ_MAX,
+        });
+
+        Ok(StatefulContext {
+            state: Mutex::new((state, None)),
+            store,
+        })
+    }
+
+    /// Creates a context from the state saved in `store`, starting from a
+    /// random clock sequence if nothing has been saved yet.
+    ///
+    /// This method requires either the `rng` or `fast-rng` feature to also be
+    /// enabled.
+    #[cfg(feature = "rng")]
+    pub fn new_random(store: S) -> io::Result<Self> {
+        StatefulContext::new(store, crate::rng::u16())
+    }
+
+    /// Returns the current state of the context.
+    pub fn state(&self) -> State {
+        self.lock().0
+    }
+
+    /// Returns the error from the last failed attempt to save the state, if
+    /// there's been one since this method was last called.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.lock().1.take()
+    }
+
+    /// Returns the store the context saves its state to.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    fn lock(&self) -> MutexGuard<'_, (State, Option<io::Error>)> {
+        // The state is always valid, even if another thread panicked
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: StateStore> ClockSequence for StatefulContext<S> {
+    fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> u16 {
+        let ticks = UUID_TICKS_BETWEEN_EPOCHS
+            .wrapping_add(seconds.wrapping_mul(10_000_000))
+            .wrapping_add(u64::from(subsec_nanos) / 100);
+
+        let mut guard = self.lock();
+        let (state, error) = &mut *guard;
+        let last = *state;
+
+        let mut next = |saved: Option<State>| {
+            // The saved state is preferred, since another process sharing
+            // the store may have updated it since this context last did
+            let mut next = saved.unwrap_or(last);
+
+            if ticks <= next.ticks {
+                next.clock_seq = next.clock_seq.wrapping_add(1) & CLOCK_SEQ_MAX;
+            }
+            next.ticks = ticks;
+
+            next
+        };
+
+        *state = match self.store.update(&mut next) {
+            Ok(next) => next,
+            Err(err) => {
+                *error = Some(err);
+                next(None)
+            }
+        };
+
+        state.clock_seq
+    }
+}
+
+/// A [`StateStore`] that keeps the state in a file.
+///
+/// The state is written as the timestamp and clock sequence in decimal,
+/// separated by a space. It's written to a temporary file first, which is
+/// then renamed over the old one, so a crash can't leave a partially written
+/// state behind.
+///
+/// On Unix platforms, saving or updating the state holds an exclusive lock
+/// on a `.lock` file next to the state, so several processes can share the
+/// same state, as long as they all use a `FileStore` for it. On other
+/// platforms, the state shouldn't be shared between processes.
+///
+/// [`StateStore`]: trait.StateStore.html
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    path: PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl FileStore {
+    /// Creates a store for the file at `path`.
+    ///
+    /// The file is created when the state is first saved.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        FileStore {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the path of the file the state is kept in.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn with_suffix(&self, suffix: &str) -> PathBuf {
+        let mut path = OsString::from(&self.path);
+        path.push(suffix);
+
+        PathBuf::from(path)
+    }
+
+    /// Takes an exclusive lock that's released when the returned file is
+    /// closed.
+    #[cfg(unix)]
+    fn lock(&self) -> io::Result<File> {
+        use crate::std::os::unix::io::AsRawFd;
+
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(self.with_suffix(".lock"))?;
+
+        loop {
+            // SAFETY: the file descriptor stays open for the duration of
+            // the call
+            if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } == 0 {
+                return Ok(file);
+            }
+
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn lock(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn write(&self, state: &State) -> io::Result<()> {
+        let temp = self.with_suffix(".tmp");
+
+        let mut file = File::create(&temp)?;
+        write!(file, "{} {}", state.ticks, state.clock_seq)?;
+        file.sync_all()?;
+
+        fs::rename(&temp, &self.path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl StateStore for FileStore {
+    fn load(&self) -> io::Result<Option<State>> {
+        // The state is only ever replaced by renaming a complete file over
+        // it, so it can be read without taking the lock
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(None)
+            }
+            Err(err) => return Err(err),
+        };
+
+        if contents.is_empty() {
+            return Ok(None);
+        }
+
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid v1 clock sequence state",
+            )
+        };
+
+        let mut parts = contents.split_whitespace();
+        let ticks = parts.next().ok_or_else(invalid)?;
+        let clock_seq = parts.next().ok_or_else(invalid)?;
+
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Some(State {
+            ticks: ticks.parse().map_err(|_| invalid())?,
+            clock_seq: clock_seq.parse().map_err(|_| invalid())?,
+        }))
+    }
+
+    fn save(&self, state: &State) -> io::Result<()> {
+        let _lock = self.lock()?;
+
+        self.write(state)
+    }
+
+    fn update(
+        &self,
+        f: &mut dyn FnMut(Option<State>) -> State,
+    ) -> io::Result<State> {
+        let _lock = self.lock()?;
+
+        let state = f(self.load()?);
+        self.write(&state)?;
+
+        Ok(state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,7 +813,7 @@ mod tests {
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::*;
 
-    use crate::{std::string::ToString, Variant};
+    use crate::{std::string::ToString, Variant, Version};
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
@@ -382,4 +888,351 @@ mod tests {
         assert_eq!(uuid3.get_timestamp().unwrap().to_rfc4122().1, 1);
         assert_eq!(uuid4.get_timestamp().unwrap().to_rfc4122().1, 2);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_new_v1_at_before_unix_epoch() {
+        use crate::std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let node = [1, 2, 3, 4, 5, 6];
+        let context = Context::new(7);
+        let time = UNIX_EPOCH - Duration::from_secs(86_400 * 365);
+
+        let uuid = Uuid::new_v1_at(time, &context, &node).unwrap();
+        let ts = uuid.get_timestamp().unwrap();
+
+        assert_eq!(ts.to_rfc4122().1, 7);
+        assert_eq!(SystemTime::from(ts), time);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_new_v1_at_out_of_range() {
+        use crate::std::time::{Duration, UNIX_EPOCH};
+
+        let node = [1, 2, 3, 4, 5, 6];
+        let context = Context::new(0);
+        let time = UNIX_EPOCH - Duration::from_secs(86_400 * 365 * 400);
+
+        assert!(Uuid::new_v1_at(time, &context, &node).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_now_v1() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let mut seen = crate::std::collections::HashSet::new();
+        let mut last = 0;
+
+        // Other tests may use the shared context at the same time, so only
+        // uniqueness and ordering are checked rather than the counter
+        for _ in 0..100 {
+            let uuid = Uuid::now_v1(&node);
+            let (ticks, _) = uuid.get_timestamp().unwrap().to_rfc4122();
+
+            assert_eq!(uuid.get_version(), Some(Version::Mac));
+            assert!(seen.insert(uuid));
+            assert!(ticks >= last);
+
+            last = ticks;
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_random_with() {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        let node = [1, 2, 3, 4, 5, 6];
+        let ts = |context: &Context| Timestamp::from_unix(context, 1, 0);
+
+        let context1 =
+            Context::new_random_with(&mut ChaCha20Rng::seed_from_u64(42));
+        let context2 =
+            Context::new_random_with(&mut ChaCha20Rng::seed_from_u64(42));
+
+        assert_eq!(
+            Uuid::new_v1(ts(&context1), &node),
+            Uuid::new_v1(ts(&context2), &node)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_tick_context() {
+        let context = TickContext::new(42);
+        let seq = |seconds, subsec_nanos| {
+            context
+                .try_timestamp(seconds, subsec_nanos)
+                .unwrap()
+                .to_rfc4122()
+        };
+
+        let tick = UUID_TICKS_BETWEEN_EPOCHS + 10 * 10_000_000;
+
+        // Time advancing keeps the clock sequence
+        assert_eq!(seq(10, 0), (tick, 42));
+        assert_eq!(seq(10, 100), (tick + 1, 42));
+        assert_eq!(seq(10, 300), (tick + 3, 42));
+
+        // Times within the same tick increment it
+        assert_eq!(seq(10, 399), (tick + 3, 43));
+        assert_eq!(seq(10, 300), (tick + 3, 44));
+
+        // Time going backwards keeps using the last tick
+        assert_eq!(seq(9, 0), (tick + 3, 45));
+
+        assert_eq!(seq(11, 0), (tick + 10_000_000, 42));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_tick_context_wraps() {
+        let context = TickContext::new(u16::MAX);
+
+        assert_eq!(context.try_timestamp(1, 0).unwrap().to_rfc4122().1, 0x3FFF);
+        assert_eq!(context.try_timestamp(1, 0).unwrap().to_rfc4122().1, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tick_context_exhausted() {
+        let context = TickContext::new(1234);
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let mut uuids = crate::std::collections::HashSet::new();
+        for _ in 0..=CLOCK_SEQ_MAX {
+            let ts = context.try_timestamp(1_000, 0).unwrap();
+
+            assert!(uuids.insert(Uuid::new_v1(ts, &node)));
+        }
+
+        let err = context.try_timestamp(1_000, 0).unwrap_err();
+        assert_eq!(err, Error(ErrorKind::ClockSequenceExhausted));
+        assert!(context.try_timestamp(999, 0).is_err());
+
+        let ts = context.try_timestamp(1_000, 100).unwrap();
+        assert!(uuids.insert(Uuid::new_v1(ts, &node)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_tick_context_blocks() {
+        use crate::std::sync::atomic::{AtomicU32, Ordering};
+
+        // A clock that only moves to the next tick after being read a few
+        // times
+        struct SlowClock(AtomicU32);
+
+        impl Clock for SlowClock {
+            fn now(&self) -> (u64, u32) {
+                let reads = self.0.fetch_add(1, Ordering::Relaxed);
+
+                (1_000, if reads < 3 { 0 } else { 100 })
+            }
+        }
+
+        let context = TickContext::new(0);
+        for _ in 0..=CLOCK_SEQ_MAX {
+            context.try_timestamp(1_000, 0).unwrap();
+        }
+
+        let clock = SlowClock(AtomicU32::new(0));
+        let ts = context.timestamp_from_clock(&clock);
+
+        assert_eq!(
+            ts.to_rfc4122(),
+            (UUID_TICKS_BETWEEN_EPOCHS + 1_000 * 10_000_000 + 1, 0)
+        );
+        assert_eq!(clock.0.load(Ordering::Relaxed), 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, Default)]
+    struct MemoryStore {
+        state: Mutex<Option<State>>,
+        fail: bool,
+    }
+
+    #[cfg(feature = "std")]
+    impl StateStore for MemoryStore {
+        fn load(&self) -> io::Result<Option<State>> {
+            Ok(*self.state.lock().unwrap())
+        }
+
+        fn save(&self, state: &State) -> io::Result<()> {
+            if self.fail {
+                return Err(io::Error::new(io::ErrorKind::Other, "full"));
+            }
+
+            *self.state.lock().unwrap() = Some(*state);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn temp_path(name: &str) -> PathBuf {
+        crate::std::env::temp_dir().join(crate::std::format!(
+            "uuid-test-{}-{}",
+            name,
+            crate::std::process::id()
+        ))
+    }
+
+    #[cfg(feature = "std")]
+    fn remove_file_store(store: &FileStore) {
+        crate::std::fs::remove_file(store.path()).unwrap();
+
+        #[cfg(unix)]
+        crate::std::fs::remove_file(store.with_suffix(".lock")).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stateful_context() {
+        let context = StatefulContext::new(MemoryStore::default(), 42).unwrap();
+        let seq = |seconds| context.generate_sequence(seconds, 0);
+
+        // Time advancing keeps the clock sequence
+        assert_eq!(seq(100), 42);
+        assert_eq!(seq(101), 42);
+        assert_eq!(seq(200), 42);
+
+        // Time going backwards or standing still changes it
+        assert_eq!(seq(150), 43);
+        assert_eq!(seq(150), 44);
+        assert_eq!(seq(151), 44);
+
+        assert_eq!(
+            context.store().load().unwrap(),
+            Some(State {
+                ticks: UUID_TICKS_BETWEEN_EPOCHS + 151 * 10_000_000,
+                clock_seq: 44,
+            })
+        );
+        assert_eq!(context.store().load().unwrap(), Some(context.state()));
+        assert!(context.take_error().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stateful_context_wraps() {
+        let context =
+            StatefulContext::new(MemoryStore::default(), u16::MAX).unwrap();
+
+        assert_eq!(context.generate_sequence(1, 0), CLOCK_SEQ_MAX);
+        assert_eq!(context.generate_sequence(1, 0), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stateful_context_save_error() {
+        let store = MemoryStore {
+            fail: true,
+            ..Default::default()
+        };
+        let context = StatefulContext::new(store, 42).unwrap();
+
+        assert_eq!(context.generate_sequence(100, 0), 42);
+        assert_eq!(context.generate_sequence(100, 0), 43);
+
+        assert!(context.take_error().is_some());
+        assert!(context.take_error().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stateful_context_restart() {
+        let path = temp_path("v1-restart");
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let before = {
+            let context =
+                StatefulContext::new(FileStore::new(&path), 42).unwrap();
+
+            Uuid::new_v1(Timestamp::from_unix(&context, 1_000, 0), &node)
+        };
+
+        // The clock went backwards while the process was down
+        let context = StatefulContext::new(FileStore::new(&path), 7).unwrap();
+        let after = Uuid::new_v1(Timestamp::from_unix(&context, 999, 0), &node);
+
+        remove_file_store(context.store());
+
+        assert_eq!(before.get_timestamp().unwrap().to_rfc4122().1, 42);
+        assert_eq!(after.get_timestamp().unwrap().to_rfc4122().1, 43);
+        assert!(context.take_error().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stateful_context_shared() {
+        let path = temp_path("v1-shared");
+
+        let a = StatefulContext::new(FileStore::new(&path), 42).unwrap();
+        let b = StatefulContext::new(FileStore::new(&path), 7).unwrap();
+
+        // Each context sees the state the other one saved, so neither reuses
+        // a clock sequence for the same timestamp
+        let seqs = [
+            a.generate_sequence(1_000, 0),
+            b.generate_sequence(1_000, 0),
+            a.generate_sequence(1_000, 0),
+        ];
+
+        remove_file_store(a.store());
+
+        assert_eq!(seqs, [42, 43, 44]);
+        assert_eq!(a.state().clock_seq, 44);
+        assert!(a.take_error().is_none());
+        assert!(b.take_error().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_file_store() {
+        let path = temp_path("v1-file-store");
+        let store = FileStore::new(&path);
+
+        assert_eq!(store.path(), path);
+        assert_eq!(store.load().unwrap(), None);
+
+        store
+            .save(&State {
+                ticks: u64::MAX,
+                clock_seq: 1,
+            })
+            .unwrap();
+        store
+            .save(&State {
+                ticks: 2,
+                clock_seq: 3,
+            })
+            .unwrap();
+
+        let loaded = store.load().unwrap();
+        let contents = crate::std::fs::read_to_string(&path).unwrap();
+        let temp_exists = store.with_suffix(".tmp").exists();
+
+        crate::std::fs::write(&path, "2 3 4").unwrap();
+        let invalid = store.load();
+
+        remove_file_store(&store);
+
+        assert_eq!(
+            loaded,
+            Some(State {
+                ticks: 2,
+                clock_seq: 3,
+            })
+        );
+        assert_eq!(contents, "2 3");
+        assert!(!temp_exists);
+        assert_eq!(invalid.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
 }
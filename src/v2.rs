@@ -0,0 +1,254 @@
+//! The implementation for Version 2 (DCE Security) UUIDs.
+//!
+//! Note that you need to enable the `v2` Cargo feature
+//! in order to use this module.
+
+use crate::{v1::Timestamp, Uuid, Version};
+
+/// The kind of local identifier stored in a V2 UUID.
+///
+/// On POSIX systems, the [`Person`] and [`Group`] domains hold a user's UID
+/// and GID respectively. The meaning of the [`Org`] domain is site-defined.
+///
+/// [`Person`]: #variant.Person
+/// [`Group`]: #variant.Group
+/// [`Org`]: #variant.Org
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Domain {
+    /// A user identifier, such as a POSIX UID.
+    Person = 0,
+    /// A group identifier, such as a POSIX GID.
+    Group,
+    /// An organization identifier.
+    Org,
+}
+
+impl Uuid {
+    /// Create a new DCE Security UUID (version 2) using a domain, local
+    /// identifier, time value + sequence, and *NodeId*.
+    ///
+    /// A V2 UUID is a V1 UUID with some of its fields repurposed: the low
+    /// 32 bits of the timestamp are replaced by `local_id`, and the low 8
+    /// bits of the clock sequence are replaced by `domain`. That means V2
+    /// UUIDs only change about every 7 minutes for the same `local_id`, and
+    /// only 64 of them can be created in that window before the clock
+    /// sequence repeats.
+    ///
+    /// The NodeID must be exactly 6 bytes long.
+    ///
+    /// Note that usage of this method requires the `v2` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{v1::{Timestamp, Context}, v2::Domain};
+    /// # use uuid::Uuid;
+    /// # fn random_seed() -> u16 { 42 }
+    /// let context = Context::new(random_seed());
+    /// let ts = Timestamp::from_unix(&context, 1497624119, 1234);
+    ///
+    /// let uuid = Uuid::new_v2(Domain::Person, 1000, ts, &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(
+    ///     uuid.hyphenated().to_string(),
+    ///     "000003e8-52a1-21e7-8000-010203040506"
+    /// );
+    /// assert_eq!(Some(Domain::Person), uuid.get_domain());
+    /// assert_eq!(Some(1000), uuid.get_local_id());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [DCE 1.1: Authentication and Security Services](https://pubs.opengroup.org/onlinepubs/9696989899/chap5.htm#tagcjh_08_02_01_01)
+    pub const fn new_v2(
+        domain: Domain,
+        local_id: u32,
+        ts: Timestamp,
+        node_id: &[u8; 6],
+    ) -> Self {
+        let (ticks, counter) = ts.to_rfc4122();
+
+        let time_mid = ((ticks >> 32) & 0xFFFF) as u16;
+        let time_high_and_version =
+            (((ticks >> 48) & 0x0FFF) as u16) | (2 << 12);
+
+        let mut d4 = [0; 8];
+
+        d4[0] = (((counter & 0x3F00) >> 8) as u8) | 0x80;
+        d4[1] = domain as u8;
+        d4[2] = node_id[0];
+        d4[3] = node_id[1];
+        d4[4] = node_id[2];
+        d4[5] = node_id[3];
+        d4[6] = node_id[4];
+        d4[7] = node_id[5];
+
+        Uuid::from_fields(local_id, time_mid, time_high_and_version, &d4)
+    }
+
+    /// Create a new DCE Security UUID (version 2) in the [`Person`] domain
+    /// using the real user ID of the current process.
+    ///
+    /// This is a convenience for calling [`Uuid::new_v2`] with the result of
+    /// `getuid`.
+    ///
+    /// Note that usage of this method requires the `v2` feature of this crate
+    /// to be enabled, and is only available on Linux.
+    ///
+    /// [`Person`]: v2/enum.Domain.html#variant.Person
+    #[cfg(target_os = "linux")]
+    pub fn new_v2_person(ts: Timestamp, node_id: &[u8; 6]) -> Self {
+        // SAFETY: `getuid` is always successful and has no preconditions
+        let uid = unsafe { libc::getuid() };
+
+        Uuid::new_v2(Domain::Person, uid, ts, node_id)
+    }
+
+    /// Create a new DCE Security UUID (version 2) in the [`Group`] domain
+    /// using the real group ID of the current process.
+    ///
+    /// This is a convenience for calling [`Uuid::new_v2`] with the result of
+    /// `getgid`.
+    ///
+    /// Note that usage of this method requires the `v2` feature of this crate
+    /// to be enabled, and is only available on Linux.
+    ///
+    /// [`Group`]: v2/enum.Domain.html#variant.Group
+    #[cfg(target_os = "linux")]
+    pub fn new_v2_group(ts: Timestamp, node_id: &[u8; 6]) -> Self {
+        // SAFETY: `getgid` is always successful and has no preconditions
+        let gid = unsafe { libc::getgid() };
+
+        Uuid::new_v2(Domain::Group, gid, ts, node_id)
+    }
+
+    /// Returns the [`Domain`] of a V2 UUID.
+    ///
+    /// Returns `None` if the supplied UUID is not V2, or if its domain is
+    /// not one of the domains defined by DCE.
+    ///
+    /// [`Domain`]: v2/enum.Domain.html
+    pub const fn get_domain(&self) -> Option<Domain> {
+        match self.get_version() {
+            Some(Version::Dce) => (),
+            _ => return None,
+        }
+
+        match self.as_bytes()[9] {
+            0 => Some(Domain::Person),
+            1 => Some(Domain::Group),
+            2 => Some(Domain::Org),
+            _ => None,
+        }
+    }
+
+    /// Returns the 32-bit local identifier of a V2 UUID, such as a POSIX
+    /// UID or GID.
+    ///
+    /// Returns `None` if the supplied UUID is not V2.
+    pub const fn get_local_id(&self) -> Option<u32> {
+        match self.get_version() {
+            Some(Version::Dce) => (),
+            _ => return None,
+        }
+
+        let b = self.as_bytes();
+
+        Some(
+            (b[0] as u32) << 24
+                | (b[1] as u32) << 16
+                | (b[2] as u32) << 8
+                | b[3] as u32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use crate::{std::string::ToString, v1::Context, Variant};
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_v2() {
+        let time: u64 = 1_496_854_535;
+        let time_fraction: u32 = 812_946_000;
+        let node = [1, 2, 3, 4, 5, 6];
+        let context = Context::new(0x1234);
+
+        let uuid = Uuid::new_v2(
+            Domain::Group,
+            0xDEAD_BEEF,
+            Timestamp::from_unix(&context, time, time_fraction),
+            &node,
+        );
+
+        assert_eq!(uuid.get_version(), Some(Version::Dce));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "deadbeef-4ba2-21e7-9201-010203040506"
+        );
+        assert_eq!(uuid.get_domain(), Some(Domain::Group));
+        assert_eq!(uuid.get_local_id(), Some(0xDEAD_BEEF));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_shares_v1_fields() {
+        let context = Context::new(42);
+        let node = [1, 2, 3, 4, 5, 6];
+        let ts = Timestamp::from_unix(&context, 1_497_624_119, 1234);
+
+        let v1 = *Uuid::new_v1(ts, &node).as_bytes();
+        let v2 = *Uuid::new_v2(Domain::Org, 7, ts, &node).as_bytes();
+
+        assert_eq!(v1[4..6], v2[4..6]);
+        assert_eq!(v1[6] & 0x0F, v2[6] & 0x0F);
+        assert_eq!(v1[7..9], v2[7..9]);
+        assert_eq!(v1[10..], v2[10..]);
+        assert_eq!(v2[9], Domain::Org as u8);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_unknown_domain() {
+        let uuid =
+            Uuid::parse_str("000003e8-52a1-21e7-8009-010203040506").unwrap();
+
+        assert_eq!(uuid.get_domain(), None);
+        assert_eq!(uuid.get_local_id(), Some(1000));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_not_v2() {
+        let uuid =
+            Uuid::parse_str("f3b4958c-52a1-11e7-802a-010203040506").unwrap();
+
+        assert_eq!(uuid.get_domain(), None);
+        assert_eq!(uuid.get_local_id(), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_new_v2_current_process() {
+        let context = Context::new(0);
+        let node = [1, 2, 3, 4, 5, 6];
+        let ts = Timestamp::from_unix(&context, 1_497_624_119, 1234);
+
+        let person = Uuid::new_v2_person(ts, &node);
+        let group = Uuid::new_v2_group(ts, &node);
+
+        assert_eq!(person.get_domain(), Some(Domain::Person));
+        assert_eq!(person.get_local_id(), Some(unsafe { libc::getuid() }));
+        assert_eq!(group.get_domain(), Some(Domain::Group));
+        assert_eq!(group.get_local_id(), Some(unsafe { libc::getgid() }));
+    }
+}
@@ -1,14 +1,48 @@
-use crate::Uuid;
+use crate::{Builder, Uuid};
+
+/// The number of UUIDs worth of random bytes retrieved at a time by
+/// [`Uuid::fill_v4`] and [`Uuid::v4_iter`].
+const BLOCK_LEN: usize = 64;
+
+/// An iterator over random UUIDs that retrieves random bytes in blocks.
+struct V4Iter {
+    block: [u8; BLOCK_LEN * 16],
+    index: usize,
+}
+
+impl Iterator for V4Iter {
+    type Item = Uuid;
+
+    fn next(&mut self) -> Option<Uuid> {
+        if self.index == BLOCK_LEN {
+            crate::rng::fill(&mut self.block);
+            self.index = 0;
+        }
+
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&self.block[self.index * 16..][..16]);
+
+        self.index += 1;
+
+        Some(Builder::from_random_bytes(bytes).into_uuid())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
 
 impl Uuid {
     /// Creates a random UUID.
     ///
     /// This uses the [`getrandom`] crate to utilise the operating system's RNG
     /// as the source of random numbers. If you'd like to use a custom
-    /// generator, don't use this method: generate random bytes using your
-    /// custom generator and pass them to the
+    /// generator, don't use this method: use [`Uuid::new_v4_with`] with the
+    /// `rand_core` feature, or generate random bytes using your custom
+    /// generator and pass them to the
     /// [`uuid::Builder::from_random_bytes`][from_random_bytes] function
-    /// instead.
+    /// instead. This method panics if the operating system's RNG fails; use
+    /// [`Uuid::try_new_v4`] to handle that instead.
     ///
     /// Note that usage of this method requires the `v4` feature of this crate
     /// to be enabled.
@@ -26,9 +60,147 @@ impl Uuid {
     ///
     /// [`getrandom`]: https://crates.io/crates/getrandom
     /// [from_random_bytes]: struct.Builder.html#method.from_random_bytes
+    /// [`Uuid::new_v4_with`]: #method.new_v4_with
+    /// [`Uuid::try_new_v4`]: #method.try_new_v4
     pub fn new_v4() -> Uuid {
         crate::Builder::from_random_bytes(crate::rng::bytes()).into_uuid()
     }
+
+    /// Fills a slice with random UUIDs.
+    ///
+    /// This produces the same kind of UUIDs as [`Uuid::new_v4`], but
+    /// retrieves random bytes for many UUIDs at once rather than one at a
+    /// time. With the default features, that means one system call per
+    /// block of UUIDs instead of one per UUID.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let mut uuids = [Uuid::nil(); 100];
+    /// Uuid::fill_v4(&mut uuids);
+    ///
+    /// assert!(uuids.iter().all(|uuid| uuid.get_version() == Some(Version::Random)));
+    /// ```
+    ///
+    /// [`Uuid::new_v4`]: #method.new_v4
+    pub fn fill_v4(uuids: &mut [Uuid]) {
+        let mut block = [0; BLOCK_LEN * 16];
+
+        for chunk in uuids.chunks_mut(BLOCK_LEN) {
+            let block = &mut block[..chunk.len() * 16];
+            crate::rng::fill(block);
+
+            for (uuid, bytes) in chunk.iter_mut().zip(block.chunks_exact(16)) {
+                let mut random_bytes = [0; 16];
+                random_bytes.copy_from_slice(bytes);
+
+                *uuid = Builder::from_random_bytes(random_bytes).into_uuid();
+            }
+        }
+    }
+
+    /// Returns an endless iterator of random UUIDs.
+    ///
+    /// This produces the same kind of UUIDs as [`Uuid::new_v4`], but
+    /// retrieves random bytes for many UUIDs at once rather than one at a
+    /// time, like [`Uuid::fill_v4`]. No random bytes are retrieved until the
+    /// first UUID is requested.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuids: Vec<Uuid> = Uuid::v4_iter().take(100).collect();
+    ///
+    /// assert_eq!(100, uuids.len());
+    /// assert!(uuids.iter().all(|uuid| uuid.get_version() == Some(Version::Random)));
+    /// ```
+    ///
+    /// [`Uuid::new_v4`]: #method.new_v4
+    /// [`Uuid::fill_v4`]: #method.fill_v4
+    pub fn v4_iter() -> impl Iterator<Item = Uuid> {
+        V4Iter {
+            block: [0; BLOCK_LEN * 16],
+            index: BLOCK_LEN,
+        }
+    }
+
+    /// Creates a random UUID, returning an error if random numbers couldn't
+    /// be retrieved.
+    ///
+    /// [`Uuid::new_v4`] panics if the operating system's RNG fails, which can
+    /// happen on some platforms if it's called before the RNG is ready. This
+    /// method returns that failure instead, so it can be retried or reported.
+    ///
+    /// This always uses the [`getrandom`] crate, even if the `fast-rng`
+    /// feature is enabled.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::try_new_v4()?;
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`getrandom`]: https://crates.io/crates/getrandom
+    /// [`Uuid::new_v4`]: #method.new_v4
+    pub fn try_new_v4() -> Result<Uuid, crate::Error> {
+        Ok(crate::Builder::from_random_bytes(crate::rng::try_bytes()?)
+            .into_uuid())
+    }
+
+    /// Creates a random UUID using the supplied random number generator.
+    ///
+    /// This can be used with a seeded generator to create reproducible
+    /// UUIDs, or with a generator that's better suited to a platform than
+    /// the operating system's RNG. Any generator implementing
+    /// [`rand_core::RngCore`] can be used.
+    ///
+    /// Note that usage of this method requires the `v4` and `rand_core`
+    /// features of this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Creating the same UUIDs from a seeded generator:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    ///
+    /// let mut rng1 = ChaCha20Rng::seed_from_u64(42);
+    /// let mut rng2 = ChaCha20Rng::seed_from_u64(42);
+    ///
+    /// let uuid = Uuid::new_v4_with(&mut rng1);
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert_eq!(uuid, Uuid::new_v4_with(&mut rng2));
+    /// ```
+    ///
+    /// [`rand_core::RngCore`]: https://docs.rs/rand_core/0.6/rand_core/trait.RngCore.html
+    #[cfg(feature = "rand_core")]
+    pub fn new_v4_with(rng: &mut impl rand_core::RngCore) -> Uuid {
+        let mut bytes = [0u8; 16];
+        rng.fill_bytes(&mut bytes);
+
+        crate::Builder::from_random_bytes(bytes).into_uuid()
+    }
 }
 
 #[cfg(test)]
@@ -57,4 +229,64 @@ mod tests {
         assert_eq!(uuid.get_version(), Some(Version::Random));
         assert_eq!(uuid.get_version_num(), 4)
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_fill() {
+        // Not a multiple of the block length, so the last block is partial
+        let mut uuids = [Uuid::nil(); BLOCK_LEN * 2 + 3];
+        Uuid::fill_v4(&mut uuids);
+
+        for (i, uuid) in uuids.iter().enumerate() {
+            assert_eq!(uuid.get_version(), Some(Version::Random));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert!(uuids[..i].iter().all(|other| other != uuid));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_iter() {
+        let uuids = Uuid::v4_iter()
+            .take(BLOCK_LEN * 2 + 3)
+            .collect::<crate::std::vec::Vec<_>>();
+
+        assert_eq!(uuids.len(), BLOCK_LEN * 2 + 3);
+
+        for (i, uuid) in uuids.iter().enumerate() {
+            assert_eq!(uuid.get_version(), Some(Version::Random));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert!(uuids[..i].iter().all(|other| other != uuid));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_try_new() {
+        let uuid = Uuid::try_new_v4().unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+    }
+
+    #[test]
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_with() {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+
+        let uuid1 = Uuid::new_v4_with(&mut rng);
+        let uuid2 = Uuid::new_v4_with(&mut rng);
+
+        assert_eq!(uuid1.get_version(), Some(Version::Random));
+        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
+        assert_ne!(uuid1, uuid2);
+
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+
+        assert_eq!(Uuid::new_v4_with(&mut rng), uuid1);
+        assert_eq!(Uuid::new_v4_with(&mut rng), uuid2);
+    }
 }
@@ -0,0 +1,268 @@
+use crate::{v1::Timestamp, Uuid, Version};
+
+impl Uuid {
+    /// Create a new UUID (version 6) using a time value + sequence +
+    /// *NodeId*.
+    ///
+    /// Version 6 UUIDs contain the same information as version 1 UUIDs, but
+    /// store the timestamp with its most significant bits first. This means
+    /// V6 UUIDs sort in the order they were created, while V1 UUIDs don't.
+    ///
+    /// The same conditions for uniqueness as [`Uuid::new_v1`] apply to this
+    /// function. The NodeID must be exactly 6 bytes long, and can be looked
+    /// up from the system's network interfaces with [`NodeId`].
+    ///
+    /// Note that usage of this method requires the `v6` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// A UUID can be created from a unix [`Timestamp`] with a
+    /// [`ClockSequence`]:
+    ///
+    /// ```rust
+    /// use uuid::v1::{Timestamp, Context};
+    /// # use uuid::Uuid;
+    /// # fn random_seed() -> u16 { 42 }
+    /// let context = Context::new(random_seed());
+    /// let ts = Timestamp::from_unix(&context, 1497624119, 1234);
+    ///
+    /// let uuid = Uuid::new_v6(ts, &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(
+    ///     uuid.hyphenated().to_string(),
+    ///     "1e752a1f-3b49-658c-802a-010203040506"
+    /// );
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Version 6 in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-5.6)
+    ///
+    /// [`Timestamp`]: timestamp/struct.Timestamp.html
+    /// [`ClockSequence`]: timestamp/trait.ClockSequence.html
+    /// [`NodeId`]: node/struct.NodeId.html
+    pub const fn new_v6(ts: Timestamp, node_id: &[u8; 6]) -> Self {
+        let (ticks, counter) = ts.to_rfc4122();
+
+        let time_high = (ticks >> 28) as u32;
+        let time_mid = ((ticks >> 12) & 0xFFFF) as u16;
+        let time_low_and_version = ((ticks & 0x0FFF) as u16) | (6 << 12);
+
+        let mut d4 = [0; 8];
+
+        d4[0] = (((counter & 0x3F00) >> 8) as u8) | 0x80;
+        d4[1] = (counter & 0xFF) as u8;
+        d4[2] = node_id[0];
+        d4[3] = node_id[1];
+        d4[4] = node_id[2];
+        d4[5] = node_id[3];
+        d4[6] = node_id[4];
+        d4[7] = node_id[5];
+
+        Uuid::from_fields(time_high, time_mid, time_low_and_version, &d4)
+    }
+
+    /// Converts a V1 UUID into a V6 UUID with the same timestamp, clock
+    /// sequence, and node.
+    ///
+    /// Returns `None` if the supplied UUID is not V1. The conversion is
+    /// lossless, so the original V1 UUID can be recovered with
+    /// [`Uuid::v6_to_v1`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let v1 = Uuid::parse_str("c232ab00-9414-11ec-b3c8-9f6bdeced846")?;
+    /// let v6 = v1.v1_to_v6().unwrap();
+    ///
+    /// assert_eq!(
+    ///     "1ec9414c-232a-6b00-b3c8-9f6bdeced846",
+    ///     v6.hyphenated().to_string(),
+    /// );
+    /// assert_eq!(v1.get_timestamp(), v6.get_timestamp());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn v1_to_v6(&self) -> Option<Uuid> {
+        let ticks = match (self.get_version(), self.get_timestamp()) {
+            (Some(Version::Mac), Some(ts)) => ts.to_rfc4122().0,
+            _ => return None,
+        };
+
+        let b = self.as_bytes();
+
+        Some(Uuid::from_bytes([
+            (ticks >> 52) as u8,
+            (ticks >> 44) as u8,
+            (ticks >> 36) as u8,
+            (ticks >> 28) as u8,
+            (ticks >> 20) as u8,
+            (ticks >> 12) as u8,
+            ((ticks >> 8) & 0x0F) as u8 | 0x60,
+            ticks as u8,
+            b[8],
+            b[9],
+            b[10],
+            b[11],
+            b[12],
+            b[13],
+            b[14],
+            b[15],
+        ]))
+    }
+
+    /// Converts a V6 UUID into a V1 UUID with the same timestamp, clock
+    /// sequence, and node.
+    ///
+    /// Returns `None` if the supplied UUID is not V6. The conversion is
+    /// lossless, so the original V6 UUID can be recovered with
+    /// [`Uuid::v1_to_v6`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let v6 = Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9f6bdeced846")?;
+    /// let v1 = v6.v6_to_v1().unwrap();
+    ///
+    /// assert_eq!(
+    ///     "c232ab00-9414-11ec-b3c8-9f6bdeced846",
+    ///     v1.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn v6_to_v1(&self) -> Option<Uuid> {
+        let ticks = match (self.get_version(), self.get_timestamp()) {
+            (Some(Version::SortMac), Some(ts)) => ts.to_rfc4122().0,
+            _ => return None,
+        };
+
+        let b = self.as_bytes();
+
+        Some(Uuid::from_bytes([
+            (ticks >> 24) as u8,
+            (ticks >> 16) as u8,
+            (ticks >> 8) as u8,
+            ticks as u8,
+            (ticks >> 40) as u8,
+            (ticks >> 32) as u8,
+            ((ticks >> 56) & 0x0F) as u8 | 0x10,
+            (ticks >> 48) as u8,
+            b[8],
+            b[9],
+            b[10],
+            b[11],
+            b[12],
+            b[13],
+            b[14],
+            b[15],
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use crate::{std::string::ToString, v1::Context, Variant};
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_v6() {
+        let time: u64 = 1_496_854_535;
+        let time_fraction: u32 = 812_946_000;
+        let node = [1, 2, 3, 4, 5, 6];
+        let context = Context::new(0);
+
+        let uuid = Uuid::new_v6(
+            Timestamp::from_unix(&context, time, time_fraction),
+            &node,
+        );
+
+        assert_eq!(uuid.get_version(), Some(Version::SortMac));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "1e74ba22-0616-6934-8000-010203040506"
+        );
+
+        let ts = uuid.get_timestamp().unwrap().to_rfc4122();
+
+        assert_eq!(ts.0 - 0x01B2_1DD2_1381_4000, 14_968_545_358_129_460);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_rfc_vector() {
+        // From RFC 9562, Appendix A.5
+        let ts = Timestamp::from_rfc4122(0x1EC_9414_C232_AB00, 0x33C8);
+        let node = [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46];
+
+        let uuid = Uuid::new_v6(ts, &node);
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "1ec9414c-232a-6b00-b3c8-9f6bdeced846"
+        );
+        assert_eq!(uuid.get_timestamp(), Some(ts));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_sorts_by_time() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        // These timestamps differ in their low bits, so the V1 UUIDs made
+        // from them sort in the opposite order
+        let uuid1 =
+            Uuid::new_v6(Timestamp::from_rfc4122(0x1_0000_0000, 0), &node);
+        let uuid2 =
+            Uuid::new_v6(Timestamp::from_rfc4122(0x0_FFFF_FFFF, 0), &node);
+
+        assert!(uuid2 < uuid1);
+        assert!(uuid2.v6_to_v1().unwrap() > uuid1.v6_to_v1().unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_v1_v6_roundtrip() {
+        let context = Context::new(42);
+        let node = [1, 2, 3, 4, 5, 6];
+
+        for &(seconds, nanos) in &[
+            (0, 0),
+            (1_496_854_535, 812_946_000),
+            (1_645_557_742, 999_999_999),
+        ] {
+            let ts = Timestamp::from_unix(&context, seconds, nanos);
+
+            let v1 = Uuid::new_v1(ts, &node);
+            let v6 = Uuid::new_v6(ts, &node);
+
+            assert_eq!(v1.v1_to_v6(), Some(v6));
+            assert_eq!(v6.v6_to_v1(), Some(v1));
+            assert_eq!(v1.get_timestamp(), v6.get_timestamp());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_conversion_wrong_version() {
+        let v4 =
+            Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+        let v6 =
+            Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9f6bdeced846").unwrap();
+
+        assert_eq!(v4.v1_to_v6(), None);
+        assert_eq!(v4.v6_to_v1(), None);
+        assert_eq!(v6.v1_to_v6(), None);
+    }
+}
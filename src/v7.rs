@@ -0,0 +1,723 @@
+//! The implementation for Version 7 UUIDs.
+//!
+//! Note that you need to enable the `v7` Cargo feature
+//! in order to use this module.
+
+use crate::{
+    error::{Error, ErrorKind},
+    std::convert::TryInto,
+    timestamp::{Clock, Timestamp},
+    Builder, Uuid, Version,
+};
+
+use atomic::Atomic;
+
+#[cfg(feature = "std")]
+use crate::timestamp::SystemClock;
+
+/// The number of bits used by the counter in [`Context`].
+const COUNTER_BITS: u32 = 12;
+
+/// The largest value the counter in [`Context`] can hold.
+const COUNTER_MAX: u64 = (1 << COUNTER_BITS) - 1;
+
+/// The largest value a freshly seeded counter in [`Context`] can hold.
+///
+/// The most significant bit of the counter is left unset when seeding so
+/// there's always room for at least half the counter's range of UUIDs within
+/// a single millisecond.
+const COUNTER_SEED_MAX: u64 = COUNTER_MAX >> 1;
+
+/// A thread-safe, stateful context for the v7 generator that guarantees
+/// every UUID it creates sorts after the ones it created before.
+///
+/// This implements the "Fixed Bit-Length Dedicated Counter" method from
+/// [RFC 9562 Section 6.2][RFC], using the 12 bits of `rand_a` as a counter.
+/// The counter is seeded with a random value whenever the millisecond
+/// changes, and incremented for each UUID created within the same
+/// millisecond. If the clock goes backwards, the context keeps using the
+/// last timestamp it saw and continues to increment the counter.
+///
+/// # Counter overflow
+///
+/// When the counter overflows within a single millisecond the context
+/// advances its timestamp by one millisecond ahead of the real time, and
+/// reseeds the counter. UUIDs created by a context that's been exhausted this
+/// way may carry a timestamp slightly in the future, but are still unique and
+/// strictly ordered. The context catches up again once the real time passes
+/// the timestamp it's using.
+///
+/// The one exception is the largest timestamp a v7 UUID can hold, in the
+/// year 10889. Advancing past it wraps the timestamp around to zero, so the
+/// UUID created after an overflow at that millisecond sorts before the ones
+/// created before it.
+///
+/// Ordering is only guaranteed between UUIDs created by the same context, so
+/// it should be shared across all threads generating v7 UUIDs that need to
+/// sort together.
+///
+/// [RFC]: https://www.rfc-editor.org/rfc/rfc9562#section-6.2
+#[derive(Debug)]
+pub struct Context {
+    // The last timestamp in the upper bits, and the last counter in the
+    // lower `COUNTER_BITS` bits.
+    state: Atomic<u64>,
+}
+
+impl Context {
+    /// Creates a thread-safe, internally mutable context that guarantees
+    /// strict ordering of the v7 UUIDs created with it.
+    pub const fn new() -> Self {
+        Context {
+            state: Atomic::new(0),
+        }
+    }
+
+    /// Returns the timestamp and counter to use for the next UUID.
+    ///
+    /// The `seed` is used for the counter if the timestamp changes.
+    fn next(&self, unix_millis: u64, seed: u16) -> (u64, u16) {
+        let unix_millis = unix_millis & 0xFFFF_FFFF_FFFF;
+        let seed = u64::from(seed) & COUNTER_SEED_MAX;
+
+        let mut current = self.state.load(atomic::Ordering::Acquire);
+
+        loop {
+            let last_millis = current >> COUNTER_BITS;
+            let last_counter = current & COUNTER_MAX;
+
+            let (millis, counter) = if unix_millis > last_millis {
+                (unix_millis, seed)
+            } else if last_counter < COUNTER_MAX {
+                (last_millis, last_counter + 1)
+            } else {
+                ((last_millis + 1) & 0xFFFF_FFFF_FFFF, seed)
+            };
+
+            match self.state.compare_exchange_weak(
+                current,
+                (millis << COUNTER_BITS) | counter,
+                atomic::Ordering::AcqRel,
+                atomic::Ordering::Acquire,
+            ) {
+                Ok(_) => return (millis, counter as u16),
+                Err(actual) => current = actual,
+            }
+        }
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context::new()
+    }
+}
+
+/// Returns the number of milliseconds elapsed since the Unix epoch according
+/// to `clock`.
+fn clock_millis(clock: impl Clock) -> u64 {
+    let (seconds, subsec_nanos) = clock.now();
+
+    seconds * 1_000 + u64::from(subsec_nanos / 1_000_000)
+}
+
+impl Uuid {
+    /// Creates a UUID (version 7) using the current system time and random
+    /// data.
+    ///
+    /// Version 7 UUIDs store a Unix timestamp in milliseconds in their most
+    /// significant 48 bits, followed by random data. UUIDs created in
+    /// different milliseconds sort in the order they were created, which
+    /// makes them better suited to database keys than random UUIDs.
+    ///
+    /// This uses the same source of random numbers as `new_v4`. If you'd like
+    /// to supply the time yourself use [`Uuid::new_v7_from_unix_millis`]
+    /// instead.
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the system time is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v7();
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Version 7 in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-5.7)
+    #[cfg(feature = "std")]
+    pub fn new_v7() -> Uuid {
+        Uuid::new_v7_from_clock(SystemClock)
+    }
+
+    /// Creates a UUID (version 7) using a Unix timestamp in milliseconds and
+    /// random data.
+    ///
+    /// Only the lower 48 bits of `unix_millis` are stored in the UUID, which
+    /// is enough to represent dates until the year 10889.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v7_from_unix_millis(1_645_557_742_000);
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// assert_eq!(Some(1_645_557_742_000), uuid.get_timestamp_millis());
+    /// ```
+    pub fn new_v7_from_unix_millis(unix_millis: u64) -> Uuid {
+        let bytes = crate::rng::bytes();
+
+        let mut random_bytes = [0; 10];
+        random_bytes.copy_from_slice(&bytes[..10]);
+
+        Builder::from_unix_timestamp_millis(unix_millis, &random_bytes)
+            .into_uuid()
+    }
+
+    /// Creates a UUID (version 7) using the current system time and the
+    /// supplied random number generator.
+    ///
+    /// This is the same as [`Uuid::new_v7`], but uses `rng` instead of the
+    /// operating system's RNG. Any generator implementing
+    /// [`rand_core::RngCore`] can be used.
+    ///
+    /// Note that usage of this method requires the `v7`, `rand_core`, and
+    /// `std` features of this crate to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the system time is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    ///
+    /// let mut rng = ChaCha20Rng::seed_from_u64(42);
+    /// let uuid = Uuid::new_v7_with(&mut rng);
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// ```
+    ///
+    /// [`rand_core::RngCore`]: https://docs.rs/rand_core/0.6/rand_core/trait.RngCore.html
+    #[cfg(all(feature = "std", feature = "rand_core"))]
+    pub fn new_v7_with(rng: &mut impl rand_core::RngCore) -> Uuid {
+        Uuid::new_v7_from_unix_millis_with(clock_millis(SystemClock), rng)
+    }
+
+    /// Creates a UUID (version 7) using a Unix timestamp in milliseconds and
+    /// the supplied random number generator.
+    ///
+    /// Together with a seeded generator, this creates the same UUIDs every
+    /// time it's called with the same timestamps, which is useful for
+    /// reproducible simulations.
+    ///
+    /// Note that usage of this method requires the `v7` and `rand_core`
+    /// features of this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    ///
+    /// let mut rng1 = ChaCha20Rng::seed_from_u64(42);
+    /// let mut rng2 = ChaCha20Rng::seed_from_u64(42);
+    ///
+    /// let uuid = Uuid::new_v7_from_unix_millis_with(1_645_557_742_000, &mut rng1);
+    ///
+    /// assert_eq!(Some(1_645_557_742_000), uuid.get_timestamp_millis());
+    /// assert_eq!(
+    ///     uuid,
+    ///     Uuid::new_v7_from_unix_millis_with(1_645_557_742_000, &mut rng2),
+    /// );
+    /// ```
+    #[cfg(feature = "rand_core")]
+    pub fn new_v7_from_unix_millis_with(
+        unix_millis: u64,
+        rng: &mut impl rand_core::RngCore,
+    ) -> Uuid {
+        let mut random_bytes = [0; 10];
+        rng.fill_bytes(&mut random_bytes);
+
+        Builder::from_unix_timestamp_millis(unix_millis, &random_bytes)
+            .into_uuid()
+    }
+
+    /// Creates a UUID (version 7) using the current time of a [`Clock`] and
+    /// random data.
+    ///
+    /// This is the same as [`Uuid::new_v7`], but reads the time from `clock`
+    /// instead of the system clock. Passing a [`ManualClock`] makes it
+    /// possible to control the time in tests.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// use uuid::timestamp::ManualClock;
+    ///
+    /// let clock = ManualClock::new(1_645_557_742, 0);
+    /// let uuid = Uuid::new_v7_from_clock(&clock);
+    ///
+    /// assert_eq!(Some(1_645_557_742_000), uuid.get_timestamp_millis());
+    /// ```
+    ///
+    /// [`Clock`]: timestamp/trait.Clock.html
+    /// [`ManualClock`]: timestamp/struct.ManualClock.html
+    pub fn new_v7_from_clock(clock: impl Clock) -> Uuid {
+        Uuid::new_v7_from_unix_millis(clock_millis(clock))
+    }
+
+    /// Creates a UUID (version 7) using a date and time and random data.
+    ///
+    /// `datetime` can be anything that converts into a [`Timestamp`], such as
+    /// a `SystemTime`, or a `chrono::DateTime` or `time::OffsetDateTime` with
+    /// the `chrono` or `time` features enabled. It's truncated to
+    /// millisecond precision.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if `datetime` can't be converted into a
+    /// [`Timestamp`], or if it's before the Unix epoch, which V7 UUIDs can't
+    /// represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let time = UNIX_EPOCH + Duration::from_millis(1_645_557_742_000);
+    /// let uuid = Uuid::new_v7_at(time)?;
+    ///
+    /// assert_eq!(Some(Version::SortRand), uuid.get_version());
+    /// assert_eq!(Some(1_645_557_742_000), uuid.get_timestamp_millis());
+    ///
+    /// assert!(Uuid::new_v7_at(UNIX_EPOCH - Duration::from_secs(1)).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Timestamp`]: timestamp/struct.Timestamp.html
+    pub fn new_v7_at<T>(datetime: T) -> Result<Uuid, Error>
+    where
+        T: TryInto<Timestamp, Error = Error>,
+    {
+        match datetime.try_into()?.to_unix_millis() {
+            Some(unix_millis) => Ok(Uuid::new_v7_from_unix_millis(unix_millis)),
+            None => Err(Error(ErrorKind::TimestampRange)),
+        }
+    }
+
+    /// Creates a UUID (version 7) using the current system time and a
+    /// [`Context`] that guarantees strict ordering.
+    ///
+    /// Every UUID created with the same `context` sorts after the UUIDs it
+    /// created before, even if they're created within the same millisecond or
+    /// the system clock goes backwards. See [`Context`] for details on how the
+    /// counter is managed.
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the system time is before the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{v7::Context, Uuid};
+    ///
+    /// let context = Context::new();
+    ///
+    /// let uuid1 = Uuid::new_v7_with_context(&context);
+    /// let uuid2 = Uuid::new_v7_with_context(&context);
+    ///
+    /// assert!(uuid1 < uuid2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn new_v7_with_context(context: &Context) -> Uuid {
+        Uuid::new_v7_from_clock_with_context(context, SystemClock)
+    }
+
+    /// Creates a UUID (version 7) using the current time of a [`Clock`] and a
+    /// [`Context`] that guarantees strict ordering.
+    ///
+    /// This is the same as [`Uuid::new_v7_with_context`], but reads the time
+    /// from `clock` instead of the system clock.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use uuid::{timestamp::ManualClock, v7::Context, Uuid};
+    ///
+    /// let context = Context::new();
+    /// let clock = ManualClock::new(1_645_557_742, 0);
+    ///
+    /// let uuid1 = Uuid::new_v7_from_clock_with_context(&context, &clock);
+    /// let uuid2 = Uuid::new_v7_from_clock_with_context(&context, &clock);
+    ///
+    /// clock.advance(Duration::from_millis(1));
+    /// let uuid3 = Uuid::new_v7_from_clock_with_context(&context, &clock);
+    ///
+    /// assert!(uuid1 < uuid2 && uuid2 < uuid3);
+    /// assert_eq!(Some(1_645_557_742_001), uuid3.get_timestamp_millis());
+    /// ```
+    ///
+    /// [`Clock`]: timestamp/trait.Clock.html
+    pub fn new_v7_from_clock_with_context(
+        context: &Context,
+        clock: impl Clock,
+    ) -> Uuid {
+        Uuid::new_v7_from_unix_millis_with_context(context, clock_millis(clock))
+    }
+
+    /// Creates a UUID (version 7) using a Unix timestamp in milliseconds and
+    /// a [`Context`] that guarantees strict ordering.
+    ///
+    /// If `unix_millis` is before the last timestamp used by `context` then
+    /// the last timestamp is used instead.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{v7::Context, Uuid};
+    ///
+    /// let context = Context::new();
+    ///
+    /// let uuid1 =
+    ///     Uuid::new_v7_from_unix_millis_with_context(&context, 1_645_557_742_000);
+    /// let uuid2 =
+    ///     Uuid::new_v7_from_unix_millis_with_context(&context, 1_645_557_742_000);
+    ///
+    /// assert!(uuid1 < uuid2);
+    /// ```
+    pub fn new_v7_from_unix_millis_with_context(
+        context: &Context,
+        unix_millis: u64,
+    ) -> Uuid {
+        let bytes = crate::rng::bytes();
+
+        let seed = (bytes[0] as u16) << 8 | (bytes[1] as u16);
+        let (millis, counter) = context.next(unix_millis, seed);
+
+        let mut random_bytes = [0; 10];
+        random_bytes[0] = (counter >> 8) as u8;
+        random_bytes[1] = counter as u8;
+        random_bytes[2..].copy_from_slice(&bytes[2..10]);
+
+        Builder::from_unix_timestamp_millis(millis, &random_bytes).into_uuid()
+    }
+
+    /// Returns the Unix timestamp in milliseconds stored in a V7 UUID.
+    ///
+    /// Returns `None` if the supplied UUID is not V7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert_eq!(Some(0x017f22e279b0), uuid.get_timestamp_millis());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn get_timestamp_millis(&self) -> Option<u64> {
+        match self.get_version() {
+            Some(Version::SortRand) => {
+                let bytes = self.as_bytes();
+
+                Some(
+                    (bytes[0] as u64) << 40
+                        | (bytes[1] as u64) << 32
+                        | (bytes[2] as u64) << 24
+                        | (bytes[3] as u64) << 16
+                        | (bytes[4] as u64) << 8
+                        | (bytes[5] as u64),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{std::string::ToString, Variant};
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new() {
+        let uuid = Uuid::new_v7_from_unix_millis(1_645_557_742_000);
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.get_version_num(), 7);
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid.get_timestamp_millis(), Some(1_645_557_742_000));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_now() {
+        let uuid = Uuid::new_v7();
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert!(uuid.get_timestamp_millis().unwrap() > 1_645_557_742_000);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_rfc_vector() {
+        // From RFC 9562, Appendix A.6
+        let uuid = Builder::from_unix_timestamp_millis(
+            0x017f22e279b0,
+            &[0x7c, 0xc3, 0x98, 0xc4, 0xdc, 0x0c, 0x0c, 0x07, 0x39, 0x8f],
+        )
+        .into_uuid();
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "017f22e2-79b0-7cc3-98c4-dc0c0c07398f"
+        );
+        assert_eq!(uuid.get_timestamp_millis(), Some(0x017f22e279b0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_sorts_by_time() {
+        let uuid1 = Uuid::new_v7_from_unix_millis(1_645_557_742_000);
+        let uuid2 = Uuid::new_v7_from_unix_millis(1_645_557_742_001);
+
+        assert!(uuid1 < uuid2);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_context_same_millis() {
+        let context = Context::new();
+        let millis = 1_645_557_742_000;
+
+        let mut last =
+            Uuid::new_v7_from_unix_millis_with_context(&context, millis);
+        assert_eq!(last.get_timestamp_millis(), Some(millis));
+
+        // Enough UUIDs to overflow the counter at least once
+        for _ in 0..10_000 {
+            let uuid =
+                Uuid::new_v7_from_unix_millis_with_context(&context, millis);
+
+            assert_eq!(uuid.get_version(), Some(Version::SortRand));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert!(uuid > last);
+
+            last = uuid;
+        }
+
+        // The timestamp has been pushed ahead of the real time
+        assert!(last.get_timestamp_millis().unwrap() > millis);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_context_clock_backwards() {
+        let context = Context::new();
+
+        let uuid1 = Uuid::new_v7_from_unix_millis_with_context(
+            &context,
+            1_645_557_742_000,
+        );
+        let uuid2 = Uuid::new_v7_from_unix_millis_with_context(
+            &context,
+            1_645_557_741_000,
+        );
+
+        assert!(uuid2 > uuid1);
+        assert_eq!(uuid2.get_timestamp_millis(), Some(1_645_557_742_000));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_context_counter_reseeds() {
+        let context = Context::new();
+
+        for millis in 1_645_557_742_000..1_645_557_742_100 {
+            let (timestamp, counter) = context.next(millis, u16::MAX);
+
+            assert_eq!(timestamp, millis);
+            assert_eq!(u64::from(counter), COUNTER_SEED_MAX);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_context_counter_overflow_wraps() {
+        let context = Context::new();
+        let millis = 0xFFFF_FFFF_FFFF;
+
+        assert_eq!(context.next(millis, 0), (millis, 0));
+        for counter in 1..=COUNTER_MAX {
+            assert_eq!(context.next(millis, 0), (millis, counter as u16));
+        }
+
+        // The timestamp wraps around instead of growing past 48 bits
+        assert_eq!(context.next(millis, 7), (0, 7));
+        assert_eq!(context.next(1_645_557_742_000, 7), (1_645_557_742_000, 7));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_context_threads() {
+        use crate::std::{sync::Arc, thread, vec::Vec};
+
+        let context = Arc::new(Context::new());
+        let millis = 1_645_557_742_000;
+
+        let handles = (0..4)
+            .map(|_| {
+                let context = context.clone();
+
+                thread::spawn(move || {
+                    (0..1_000)
+                        .map(|_| {
+                            Uuid::new_v7_from_unix_millis_with_context(
+                                &context, millis,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut uuids = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        uuids.sort();
+        uuids.dedup();
+
+        assert_eq!(uuids.len(), 4_000);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_timestamp_millis_not_v7() {
+        let uuid =
+            Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        assert_eq!(uuid.get_timestamp_millis(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_with_rng() {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+        let uuid1 =
+            Uuid::new_v7_from_unix_millis_with(1_645_557_742_000, &mut rng);
+        let uuid2 =
+            Uuid::new_v7_from_unix_millis_with(1_645_557_742_000, &mut rng);
+
+        assert_eq!(uuid1.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid1.get_timestamp_millis(), Some(1_645_557_742_000));
+        assert_ne!(uuid1, uuid2);
+
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+
+        assert_eq!(
+            Uuid::new_v7_from_unix_millis_with(1_645_557_742_000, &mut rng),
+            uuid1
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_manual_clock_ordering() {
+        use crate::{std::time::Duration, timestamp::ManualClock};
+
+        let context = Context::new();
+        let clock = ManualClock::new(1_645_557_742, 0);
+
+        let mut last = Uuid::new_v7_from_clock_with_context(&context, &clock);
+
+        for i in 0..1_000u64 {
+            // Step the clock every 100 UUIDs, and go backwards once
+            if i == 500 {
+                clock.set(1_645_557_741, 0);
+            } else if i % 100 == 0 {
+                clock.advance(Duration::from_millis(1));
+            }
+
+            let uuid = Uuid::new_v7_from_clock_with_context(&context, &clock);
+
+            assert!(uuid > last);
+            last = uuid;
+        }
+
+        assert_eq!(
+            Uuid::new_v7_from_clock(&clock).get_timestamp_millis(),
+            Some(1_645_557_741_004)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_new_v7_at() {
+        use crate::std::time::{Duration, UNIX_EPOCH};
+
+        let time = UNIX_EPOCH + Duration::new(1_645_557_742, 999_999);
+        let uuid = Uuid::new_v7_at(time).unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid.get_timestamp_millis(), Some(1_645_557_742_000));
+
+        let err = Uuid::new_v7_at(UNIX_EPOCH - Duration::from_millis(1));
+
+        assert!(err.is_err());
+    }
+}
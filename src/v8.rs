@@ -0,0 +1,391 @@
+use crate::{Builder, Uuid, Version};
+
+#[cfg(feature = "v8-sha2")]
+use sha2::{Digest, Sha256, Sha512};
+
+impl Uuid {
+    /// Creates a custom UUID comprised almost entirely of user-supplied bytes.
+    ///
+    /// This will inject the UUID Version at 4 bits starting at the 48th bit
+    /// and the Variant into 2 bits 64th bit. Any existing bits in the
+    /// user-supplied bytes at those locations will be overridden.
+    ///
+    /// To store structured data without losing any of it to the version and
+    /// variant, use [`Builder::from_custom_fields`] instead.
+    ///
+    /// Note that usage of this method requires the `v8` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let buf: [u8; 16] = *b"abcdefghijklmnop";
+    /// let uuid = Uuid::new_v8(buf);
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Version 8 in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#section-5.8)
+    ///
+    /// [`Builder::from_custom_fields`]: struct.Builder.html#method.from_custom_fields
+    pub const fn new_v8(buf: [u8; 16]) -> Uuid {
+        Builder::from_custom_bytes(buf).into_uuid()
+    }
+
+    /// Returns the `custom_a`, `custom_b`, and `custom_c` fields of a
+    /// version 8 UUID.
+    ///
+    /// This is the inverse of [`Builder::from_custom_fields`]. `custom_a`
+    /// holds 48 bits, `custom_b` 12 bits, and `custom_c` 62 bits; the version
+    /// and variant bits aren't included in any of them.
+    ///
+    /// Returns `None` if the supplied UUID is not V8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("2489e9ad-2ee2-8e00-8ec9-32d5f69181c0")?;
+    ///
+    /// assert_eq!(
+    ///     Some((0x2489_e9ad_2ee2, 0xe00, 0x0ec9_32d5_f691_81c0)),
+    ///     uuid.get_custom_fields(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Builder::from_custom_fields`]: struct.Builder.html#method.from_custom_fields
+    pub const fn get_custom_fields(&self) -> Option<(u64, u16, u64)> {
+        match self.get_version() {
+            Some(Version::Custom) => (),
+            _ => return None,
+        }
+
+        let b = self.as_bytes();
+
+        let custom_a = (b[0] as u64) << 40
+            | (b[1] as u64) << 32
+            | (b[2] as u64) << 24
+            | (b[3] as u64) << 16
+            | (b[4] as u64) << 8
+            | b[5] as u64;
+        let custom_b = ((b[6] & 0x0F) as u16) << 8 | b[7] as u16;
+        let custom_c = ((b[8] & 0x3F) as u64) << 56
+            | (b[9] as u64) << 48
+            | (b[10] as u64) << 40
+            | (b[11] as u64) << 32
+            | (b[12] as u64) << 24
+            | (b[13] as u64) << 16
+            | (b[14] as u64) << 8
+            | b[15] as u64;
+
+        Some((custom_a, custom_b, custom_c))
+    }
+}
+
+#[cfg(feature = "v8-sha2")]
+impl Uuid {
+    /// Creates a version 8 UUID using a name from a namespace, based on the
+    /// SHA-256 hash.
+    ///
+    /// This works like [`Uuid::new_v5`], but uses SHA-256 instead of SHA-1,
+    /// following the name-based example in RFC 9562. The first 16 bytes of
+    /// the hash of the namespace followed by the name are used, with the
+    /// version and variant set.
+    ///
+    /// A number of namespaces are available as constants in this crate:
+    ///
+    /// * [`NAMESPACE_DNS`]
+    /// * [`NAMESPACE_OID`]
+    /// * [`NAMESPACE_URL`]
+    /// * [`NAMESPACE_X500`]
+    ///
+    /// Note that usage of this method requires the `v8-sha2` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Generating a SHA-256 DNS UUID for `www.example.com`:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v8_sha256(&Uuid::NAMESPACE_DNS, b"www.example.com");
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(
+    ///     "5c146b14-3c52-8afd-938a-375d0df1fbf6",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Name-based UUIDv8 example in RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#appendix-B.2)
+    ///
+    /// [`Uuid::new_v5`]: struct.Uuid.html#method.new_v5
+    /// [`NAMESPACE_DNS`]: struct.Uuid.html#associatedconst.NAMESPACE_DNS
+    /// [`NAMESPACE_OID`]: struct.Uuid.html#associatedconst.NAMESPACE_OID
+    /// [`NAMESPACE_URL`]: struct.Uuid.html#associatedconst.NAMESPACE_URL
+    /// [`NAMESPACE_X500`]: struct.Uuid.html#associatedconst.NAMESPACE_X500
+    pub fn new_v8_sha256(namespace: &Uuid, name: &[u8]) -> Uuid {
+        let mut hasher = Sha256::new();
+
+        hasher.update(namespace.as_bytes());
+        hasher.update(name);
+
+        Uuid::from_hash(&hasher.finalize())
+    }
+
+    /// Creates a version 8 UUID using a name from a namespace, based on the
+    /// SHA-512 hash.
+    ///
+    /// This is the same as [`Uuid::new_v8_sha256`], but uses SHA-512. The two
+    /// functions produce different UUIDs for the same namespace and name.
+    ///
+    /// Note that usage of this method requires the `v8-sha2` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Generating a SHA-512 DNS UUID for `www.example.com`:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v8_sha512(&Uuid::NAMESPACE_DNS, b"www.example.com");
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(
+    ///     "94ee4ddb-9f36-8018-9ccf-86a4441691e0",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`Uuid::new_v8_sha256`]: #method.new_v8_sha256
+    pub fn new_v8_sha512(namespace: &Uuid, name: &[u8]) -> Uuid {
+        let mut hasher = Sha512::new();
+
+        hasher.update(namespace.as_bytes());
+        hasher.update(name);
+
+        Uuid::from_hash(&hasher.finalize())
+    }
+
+    fn from_hash(hash: &[u8]) -> Uuid {
+        let mut bytes = crate::Bytes::default();
+        bytes.copy_from_slice(&hash[..16]);
+
+        Builder::from_custom_bytes(bytes).into_uuid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    use crate::{std::string::ToString, ErrorKind, Variant};
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new() {
+        let buf: [u8; 16] = [
+            0xf, 0xe, 0xd, 0xc, 0xb, 0xa, 0x9, 0x8, 0x7, 0x6, 0x5, 0x4, 0x3,
+            0x2, 0x1, 0x0,
+        ];
+        let uuid = Uuid::new_v8(buf);
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid.get_version_num(), 8);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "0f0e0d0c-0b0a-8908-8706-050403020100"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_rfc_vector() {
+        // From RFC 9562, Appendix B.1
+        let uuid = Builder::from_custom_fields(
+            0x2489_E9AD_2EE2,
+            0xE00,
+            0x0EC9_32D5_F691_81C0,
+        )
+        .unwrap()
+        .into_uuid();
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "2489e9ad-2ee2-8e00-8ec9-32d5f69181c0"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_custom_fields_roundtrip() {
+        for &fields in &[
+            (0, 0, 0),
+            (0xFFFF_FFFF_FFFF, 0xFFF, 0x3FFF_FFFF_FFFF_FFFF),
+            (0x1234_5678_9ABC, 0xDEF, 0x0123_4567_89AB_CDEF),
+        ] {
+            let uuid =
+                Builder::from_custom_fields(fields.0, fields.1, fields.2)
+                    .unwrap()
+                    .into_uuid();
+
+            assert_eq!(uuid.get_version(), Some(Version::Custom));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert_eq!(uuid.get_custom_fields(), Some(fields));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_custom_fields_too_wide() {
+        let err = Builder::from_custom_fields(1 << 48, 0, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid custom field length in custom_a: expected at most 48 bits, found 49"
+        );
+
+        let err = Builder::from_custom_fields(0, 0xF000, 0).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::CustomFieldLength {
+                field: "custom_b",
+                expected: 12,
+                len: 16,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid custom field length in custom_b: expected at most 12 bits, found 16"
+        );
+
+        let err = Builder::from_custom_fields(0, 0, 1 << 62).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid custom field length in custom_c: expected at most 62 bits, found 63"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_custom_fields_not_v8() {
+        let uuid =
+            Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        assert_eq!(uuid.get_custom_fields(), None);
+    }
+
+    // The expected UUIDs are derived with `sha256sum` over the namespace's 16
+    // bytes followed by the name. The first 32 hex digits of the digest are
+    // kept, hex digit 13 is replaced with the version `8`, and the two most
+    // significant bits of hex digit 17 are set to the variant `10`.
+    //
+    // The first row is also the test vector in RFC 9562, Appendix B.2.
+    #[cfg(feature = "v8-sha2")]
+    static SHA256_FIXTURE: &[(&Uuid, &str, &str)] = &[
+        // sha256sum: 5c146b143c524afd938a375d0df1fbf6...
+        (
+            &Uuid::NAMESPACE_DNS,
+            "www.example.com",
+            "5c146b14-3c52-8afd-938a-375d0df1fbf6",
+        ),
+        // sha256sum: 4da0170713650fb24751c9b55bf38ba8...
+        (
+            &Uuid::NAMESPACE_DNS,
+            "rust-lang.org",
+            "4da01707-1365-8fb2-8751-c9b55bf38ba8",
+        ),
+        // sha256sum: b31aedee450a94ded880e238dc547a04...
+        (
+            &Uuid::NAMESPACE_URL,
+            "https://www.example.com/",
+            "b31aedee-450a-84de-9880-e238dc547a04",
+        ),
+        // sha256sum: e1b4fc00f0de047d9f34ae63d2eb3238...
+        (
+            &Uuid::NAMESPACE_OID,
+            "1.3.6.1",
+            "e1b4fc00-f0de-847d-9f34-ae63d2eb3238",
+        ),
+        // sha256sum: a67a879a4304cb51d94ff05802360fa5...
+        (
+            &Uuid::NAMESPACE_X500,
+            "cn=John Doe",
+            "a67a879a-4304-8b51-994f-f05802360fa5",
+        ),
+    ];
+
+    // Derived the same way as `SHA256_FIXTURE`, but with `sha512sum`.
+    #[cfg(feature = "v8-sha2")]
+    static SHA512_FIXTURE: &[(&Uuid, &str, &str)] = &[
+        // sha512sum: 94ee4ddb9f36d018dccf86a4441691e0...
+        (
+            &Uuid::NAMESPACE_DNS,
+            "www.example.com",
+            "94ee4ddb-9f36-8018-9ccf-86a4441691e0",
+        ),
+        // sha512sum: d7de3ad28d80309a6c0f1dbcca66d0e7...
+        (
+            &Uuid::NAMESPACE_DNS,
+            "rust-lang.org",
+            "d7de3ad2-8d80-809a-ac0f-1dbcca66d0e7",
+        ),
+        // sha512sum: d6add4ca7fcd531a14a976540b96e890...
+        (
+            &Uuid::NAMESPACE_URL,
+            "https://www.example.com/",
+            "d6add4ca-7fcd-831a-94a9-76540b96e890",
+        ),
+        // sha512sum: 1c7956d98aaa668bf6e2ae479ff126bd...
+        (
+            &Uuid::NAMESPACE_OID,
+            "1.3.6.1",
+            "1c7956d9-8aaa-868b-b6e2-ae479ff126bd",
+        ),
+        // sha512sum: ddc3e362d033aa6917de62437eac6212...
+        (
+            &Uuid::NAMESPACE_X500,
+            "cn=John Doe",
+            "ddc3e362-d033-8a69-97de-62437eac6212",
+        ),
+    ];
+
+    #[test]
+    #[cfg(feature = "v8-sha2")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_sha256() {
+        for &(ns, name, expected) in SHA256_FIXTURE {
+            let uuid = Uuid::new_v8_sha256(ns, name.as_bytes());
+
+            assert_eq!(uuid.get_version(), Some(Version::Custom));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert_eq!(uuid.hyphenated().to_string(), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "v8-sha2")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_sha512() {
+        for &(ns, name, expected) in SHA512_FIXTURE {
+            let uuid = Uuid::new_v8_sha512(ns, name.as_bytes());
+
+            assert_eq!(uuid.get_version(), Some(Version::Custom));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert_eq!(uuid.hyphenated().to_string(), expected);
+        }
+    }
+}
@@ -12,6 +12,10 @@ const _: Uuid = uuid!("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8");
 const _: Uuid = uuid!("00000000000000000000000000000000");
 const _: Uuid = uuid!("00000000-0000-0000-0000-000000000000");
 
+// Max
+const _: Uuid = uuid!("ffffffffffffffffffffffffffffffff");
+const _: Uuid = uuid!("FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF");
+
 // valid hyphenated
 const _: Uuid = uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
 // valid short